@@ -25,4 +25,28 @@ pub enum AccessMintError {
     
     #[msg("Numerical overflow")]
     NumericalOverflow,
+    
+    #[msg("Metadata name, symbol or uri is too long")]
+    MetadataTooLong,
+    
+    #[msg("Invalid access duration")]
+    InvalidAccessDuration,
+    
+    #[msg("Access mint grants permanent access and cannot be renewed")]
+    NotRenewable,
+    
+    #[msg("Access has been revoked")]
+    AccessRevoked,
+    
+    #[msg("Missing buyer token account")]
+    MissingTokenAccount,
+    
+    #[msg("Max supply must be greater than zero")]
+    InvalidMaxSupply,
+    
+    #[msg("Access mint is sold out")]
+    SoldOut,
+    
+    #[msg("Platform is paused")]
+    PlatformPaused,
 }
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::{AccessConfig, AccessTokenMetadata};
+
+/// Emitted when a creator sets up an access mint for their content
+#[event]
+pub struct AccessMintInitialized {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Creator who owns the content
+    pub creator: Pubkey,
+    
+    /// Content the access mint belongs to
+    pub content_id: [u8; 32],
+    
+    /// Seed used for PDA derivation
+    pub seed: u64,
+    
+    /// Access token mint
+    pub mint: Pubkey,
+    
+    /// Authority allowed to mint access
+    pub minter: Pubkey,
+    
+    /// Transfer mode, access period and supply cap
+    pub config: AccessConfig,
+    
+    /// Time the access mint was created
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer is granted access to content
+#[event]
+pub struct AccessMinted {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Access token mint
+    pub mint: Pubkey,
+    
+    /// Buyer receiving access
+    pub buyer: Pubkey,
+    
+    /// Buyer's access record PDA
+    pub access_record: Pubkey,
+    
+    /// Escrow the purchase was paid through
+    pub escrow: Pubkey,
+    
+    /// Price paid for access
+    pub price_paid: u64,
+    
+    /// Edition number of this purchase
+    pub edition: u64,
+    
+    /// When access expires (0 = never)
+    pub expires_at: i64,
+    
+    /// Time of the purchase
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer's access is extended by a renewal
+#[event]
+pub struct AccessRenewed {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Buyer whose access was renewed
+    pub buyer: Pubkey,
+    
+    /// Buyer's access record PDA
+    pub access_record: Pubkey,
+    
+    /// New expiry of the access
+    pub expires_at: i64,
+    
+    /// Time of the renewal
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer's access is revoked and their access token burned
+#[event]
+pub struct AccessRevoked {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Access token mint
+    pub mint: Pubkey,
+    
+    /// Buyer whose access was revoked
+    pub buyer: Pubkey,
+    
+    /// Buyer's access record PDA
+    pub access_record: Pubkey,
+    
+    /// Time of the revocation
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator rotates the authorized minter
+#[event]
+pub struct MinterUpdated {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Minter before the change
+    pub old_minter: Pubkey,
+    
+    /// Minter after the change
+    pub new_minter: Pubkey,
+    
+    /// Time of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator updates the access token metadata
+#[event]
+pub struct MetadataUpdated {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// New name, symbol and uri
+    pub metadata: AccessTokenMetadata,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Check whether a buyer currently has access
+/// Returns false when the buyer never purchased or their access has expired or been revoked;
+/// verifiers can simulate this instruction or call it via CPI and read the return value
+pub fn has_valid_access(ctx: Context<HasValidAccess>) -> Result<bool> {
+    let record_info = ctx.accounts.access_record.to_account_info();
+    
+    // No record means the buyer never purchased
+    if record_info.owner != ctx.program_id || record_info.data_is_empty() {
+        return Ok(false);
+    }
+    
+    let access_record = {
+        let data = record_info.try_borrow_data()?;
+        AccessRecord::try_deserialize(&mut &data[..])?
+    };
+    let now = Clock::get()?.unix_timestamp;
+    let valid = access_record.is_valid(now);
+    
+    msg!("Access for buyer {}: valid={}, expires_at={}", 
+        ctx.accounts.buyer.key(), valid, access_record.expires_at);
+    
+    Ok(valid)
+}
+
+#[derive(Accounts)]
+pub struct HasValidAccess<'info> {
+    /// The buyer being checked
+    /// CHECK: Only used to derive the access record
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Buyer's access record PDA (may not exist)
+    /// CHECK: PDA validated by seeds, deserialized only if it exists
+    #[account(
+        seeds = [
+            AccessRecord::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub access_record: UncheckedAccount<'info>,
+}
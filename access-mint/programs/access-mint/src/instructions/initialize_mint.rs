@@ -1,13 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3, Metadata};
 use anchor_spl::token::{Mint, Token};
 use crate::state::*;
+use crate::events::*;
 
 /// Initialize a new access token mint for a specific content
 pub fn initialize_mint(
     ctx: Context<InitializeMint>,
     content_id: [u8; 32],
     seed: u64,
+    minter: Pubkey,
+    metadata: AccessTokenMetadata,
+    config: AccessConfig,
 ) -> Result<()> {
+    metadata.validate()?;
+    config.validate()?;
+    
     let access_mint_state = &mut ctx.accounts.access_mint_state;
     let clock = Clock::get()?;
     
@@ -16,19 +24,67 @@ pub fn initialize_mint(
     access_mint_state.content_id = content_id;
     access_mint_state.mint = ctx.accounts.mint.key();
     access_mint_state.mint_authority = ctx.accounts.mint_authority.key();
+    access_mint_state.minter = minter;
     access_mint_state.seed = seed;
     access_mint_state.total_minted = 0;
+    access_mint_state.active_supply = 0;
     access_mint_state.created_ts = clock.unix_timestamp;
+    access_mint_state.transfer_mode = config.transfer_mode;
+    access_mint_state.access_duration_secs = config.access_duration_secs;
+    access_mint_state.max_supply = config.max_supply;
     access_mint_state.bump = ctx.bumps.access_mint_state;
     
-    msg!("Access mint initialized for creator: {}, content_id: {:?}", 
-        ctx.accounts.creator.key(), content_id);
+    // Write the Metaplex metadata account; the mint authority PDA is its update authority
+    let creator_key = ctx.accounts.creator.key();
+    let seed_bytes = seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        creator_key.as_ref(),
+        content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+    
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: ctx.accounts.creator.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &[&authority_seeds[..]],
+        ),
+        metadata.to_data_v2(),
+        true,  // is_mutable, so the creator can call update_metadata
+        true,  // update authority signs
+        None,
+    )?;
+    
+    msg!("Access mint initialized for creator: {}, content_id: {:?}, minter: {}, config: {:?}", 
+        ctx.accounts.creator.key(), content_id, minter, config);
+    
+    emit_cpi!(AccessMintInitialized {
+        access_mint_state: ctx.accounts.access_mint_state.key(),
+        creator: creator_key,
+        content_id,
+        seed,
+        mint: ctx.accounts.mint.key(),
+        minter,
+        config,
+        timestamp: clock.unix_timestamp,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(content_id: [u8; 32], seed: u64)]
+#[instruction(content_id: [u8; 32], seed: u64, minter: Pubkey)]
 pub struct InitializeMint<'info> {
     /// The creator who owns the content
     #[account(mut)]
@@ -72,6 +128,19 @@ pub struct InitializeMint<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
     
+    /// Metaplex metadata account for the mint
+    /// CHECK: PDA validated by seeds, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Metaplex token metadata program
+    pub token_metadata_program: Program<'info, Metadata>,
+    
     /// Token program
     pub token_program: Program<'info, Token>,
     
@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, ThawAccount, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
+use distribution::state::PlatformConfig;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
-/// Mint an access token to a buyer
-/// This is typically called via CPI from the payment escrow program
-pub fn mint_access(ctx: Context<MintAccess>) -> Result<()> {
+/// Grant access to a buyer: record the purchase and mint an access token
+/// This is typically called via CPI from the payment escrow program,
+/// which signs as the authorized minter once payment has been received.
+/// `RecordOnly` mints skip the token and rely on the access record alone
+pub fn mint_access(ctx: Context<MintAccess>, escrow: Pubkey, price_paid: u64) -> Result<()> {
+    // Emergency stop: no new access is granted while the platform is paused
+    require!(
+        !ctx.accounts.platform_config.paused,
+        AccessMintError::PlatformPaused
+    );
+    
     let access_mint_state = &mut ctx.accounts.access_mint_state;
+    let now = Clock::get()?.unix_timestamp;
     
     // Verify mint matches state
     require!(
@@ -15,64 +26,135 @@ pub fn mint_access(ctx: Context<MintAccess>) -> Result<()> {
         AccessMintError::InvalidMint
     );
     
-    // Get PDA signer seeds
-    let creator = access_mint_state.creator;
-    let content_id = access_mint_state.content_id;
-    let seed = access_mint_state.seed;
-    let seed_bytes = seed.to_le_bytes();
-    
-    // Derive the bump manually since we're using UncheckedAccount
-    let (expected_authority, authority_bump) = Pubkey::find_program_address(
-        &[
-            AccessMintState::AUTHORITY_SEED_PREFIX,
-            creator.as_ref(),
-            content_id.as_ref(),
-            seed_bytes.as_ref(),
-        ],
-        ctx.program_id,
+    // Limited editions: refuse once every edition has been sold
+    require!(
+        !access_mint_state.is_sold_out(),
+        AccessMintError::SoldOut
     );
     
-    // Verify the mint authority matches
+    // One purchase per wallet: an existing record must have lapsed or been revoked
+    let access_record = &mut ctx.accounts.access_record;
+    let is_new_record = access_record.buyer == Pubkey::default();
     require!(
-        ctx.accounts.mint_authority.key() == expected_authority,
-        AccessMintError::InvalidMintAuthority
+        is_new_record || !access_record.is_valid(now),
+        AccessMintError::AlreadyMinted
     );
     
-    let authority_seeds = &[
-        AccessMintState::AUTHORITY_SEED_PREFIX,
-        creator.as_ref(),
-        content_id.as_ref(),
-        seed_bytes.as_ref(),
-        &[authority_bump],
-    ];
-    let signer_seeds = &[&authority_seeds[..]];
-    
-    // Mint 1 access token to buyer (decimals = 0, so amount = 1)
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        1, // Mint 1 token (with 0 decimals)
-    )?;
-    
-    // Update total minted count
+    // Update total minted count; the new count is this purchase's edition number
     access_mint_state.total_minted = access_mint_state
         .total_minted
         .checked_add(1)
         .ok_or(AccessMintError::NumericalOverflow)?;
     
-    msg!("Access token minted to buyer: {}, total minted: {}", 
-        ctx.accounts.buyer.key(), access_mint_state.total_minted);
+    // A lapsed record still holds its edition; a new or revoked one takes a free edition
+    if is_new_record || access_record.revoked {
+        access_mint_state.active_supply = access_mint_state
+            .active_supply
+            .checked_add(1)
+            .ok_or(AccessMintError::NumericalOverflow)?;
+    }
+    
+    // Record the purchase
+    access_record.access_mint_state = access_mint_state.key();
+    access_record.buyer = ctx.accounts.buyer.key();
+    access_record.escrow = escrow;
+    access_record.price_paid = price_paid;
+    access_record.purchased_ts = now;
+    access_record.edition = access_mint_state.total_minted;
+    access_record.expires_at = access_mint_state.extend_expiry(0, now)?;
+    access_record.revoked = false;
+    access_record.bump = ctx.bumps.access_record;
+    
+    if access_mint_state.transfer_mode != TransferMode::RecordOnly {
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(AccessMintError::MissingTokenAccount)?;
+        
+        // Get PDA signer seeds
+        let creator = access_mint_state.creator;
+        let content_id = access_mint_state.content_id;
+        let seed_bytes = access_mint_state.seed.to_le_bytes();
+        
+        // Derive the bump manually since we're using UncheckedAccount
+        let (expected_authority, authority_bump) = access_mint_state.find_mint_authority(ctx.program_id);
+        
+        // Verify the mint authority matches
+        require!(
+            ctx.accounts.mint_authority.key() == expected_authority,
+            AccessMintError::InvalidMintAuthority
+        );
+        
+        let authority_seeds = &[
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            creator.as_ref(),
+            content_id.as_ref(),
+            seed_bytes.as_ref(),
+            &[authority_bump],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+        
+        // A soulbound account from an earlier purchase must be thawed before it can receive tokens
+        if buyer_token_account.is_frozen() {
+            token::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ThawAccount {
+                    account: buyer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+        
+        // Mint 1 access token to buyer (decimals = 0, so amount = 1)
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: buyer_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1, // Mint 1 token (with 0 decimals)
+        )?;
+        
+        // Soulbound tokens: freeze the buyer account so the token cannot be transferred
+        if access_mint_state.transfer_mode == TransferMode::Frozen {
+            token::freeze_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                FreezeAccount {
+                    account: buyer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+    }
+    
+    msg!("Access granted to buyer: {}, edition: {}, expires_at: {}", 
+        ctx.accounts.buyer.key(), access_record.edition, access_record.expires_at);
+    
+    emit_cpi!(AccessMinted {
+        access_mint_state: access_mint_state.key(),
+        mint: access_mint_state.mint,
+        buyer: access_record.buyer,
+        access_record: access_record.key(),
+        escrow,
+        price_paid,
+        edition: access_record.edition,
+        expires_at: access_record.expires_at,
+        timestamp: now,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MintAccess<'info> {
     /// The buyer receiving the access token
@@ -83,6 +165,12 @@ pub struct MintAccess<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     
+    /// Authorized minter recorded in the access mint state
+    #[account(
+        constraint = minter.key() == access_mint_state.minter @ AccessMintError::Unauthorized,
+    )]
+    pub minter: Signer<'info>,
+    
     /// Access mint state PDA
     #[account(
         mut,
@@ -96,6 +184,14 @@ pub struct MintAccess<'info> {
     )]
     pub access_mint_state: Account<'info, AccessMintState>,
     
+    /// Platform config (distribution program) holding the pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     /// The mint account
     #[account(mut)]
     pub mint: Account<'info, Mint>,
@@ -105,14 +201,29 @@ pub struct MintAccess<'info> {
     pub mint_authority: UncheckedAccount<'info>,
     
     /// Buyer's token account (ATA)
-    /// Will be created if it doesn't exist
+    /// Will be created if it doesn't exist; omitted for `RecordOnly` mints
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
         associated_token::authority = buyer,
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Buyer's access record PDA
+    /// Will be created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AccessRecord::LEN,
+        seeds = [
+            AccessRecord::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub access_record: Account<'info, AccessRecord>,
     
     /// Token program
     pub token_program: Program<'info, Token>,
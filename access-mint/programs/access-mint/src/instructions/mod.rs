@@ -1,5 +1,15 @@
 pub mod initialize_mint;
 pub mod mint_access;
+pub mod renew_access;
+pub mod has_valid_access;
+pub mod revoke_access;
+pub mod set_minter;
+pub mod update_metadata;
 
 pub use initialize_mint::*;
 pub use mint_access::*;
+pub use renew_access::*;
+pub use has_valid_access::*;
+pub use revoke_access::*;
+pub use set_minter::*;
+pub use update_metadata::*;
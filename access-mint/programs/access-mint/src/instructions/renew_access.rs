@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Extend a buyer's access by one access period
+/// Called via CPI from the payment escrow program once a renewal has been paid;
+/// requires the authorized minter
+pub fn renew_access(ctx: Context<RenewAccess>) -> Result<()> {
+    let access_mint_state = &ctx.accounts.access_mint_state;
+    
+    require!(
+        access_mint_state.access_duration_secs > 0,
+        AccessMintError::NotRenewable
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let access_record = &mut ctx.accounts.access_record;
+    require!(!access_record.revoked, AccessMintError::AccessRevoked);
+    access_record.expires_at = access_mint_state.extend_expiry(access_record.expires_at, now)?;
+    
+    msg!("Access renewed for buyer: {}, expires_at: {}", 
+        ctx.accounts.buyer.key(), access_record.expires_at);
+    
+    emit_cpi!(AccessRenewed {
+        access_mint_state: access_mint_state.key(),
+        buyer: access_record.buyer,
+        access_record: access_record.key(),
+        expires_at: access_record.expires_at,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenewAccess<'info> {
+    /// The buyer whose access is renewed
+    /// CHECK: Only used to derive the access record
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Authorized minter recorded in the access mint state
+    #[account(
+        constraint = minter.key() == access_mint_state.minter @ AccessMintError::Unauthorized,
+    )]
+    pub minter: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Buyer's access record PDA (created by the original purchase)
+    #[account(
+        mut,
+        seeds = [
+            AccessRecord::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump = access_record.bump,
+    )]
+    pub access_record: Account<'info, AccessRecord>,
+}
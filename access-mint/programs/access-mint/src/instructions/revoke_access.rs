@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, ThawAccount, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Revoke a buyer's access: mark the access record revoked, burn the access token
+/// and give its edition back to the supply
+/// Called via CPI from the payment escrow program when a purchase is refunded;
+/// requires both the authorized minter and the buyer (token owner) to sign
+pub fn revoke_access(ctx: Context<RevokeAccess>) -> Result<()> {
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    
+    // Verify mint matches state
+    require!(
+        ctx.accounts.mint.key() == access_mint_state.mint,
+        AccessMintError::InvalidMint
+    );
+    require!(
+        !ctx.accounts.access_record.revoked,
+        AccessMintError::AccessRevoked
+    );
+    
+    if access_mint_state.transfer_mode != TransferMode::RecordOnly {
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(AccessMintError::MissingTokenAccount)?;
+        
+        // Frozen (soulbound) accounts cannot be burned from, so thaw first
+        if buyer_token_account.is_frozen() {
+            let (expected_authority, authority_bump) = access_mint_state.find_mint_authority(ctx.program_id);
+            require!(
+                ctx.accounts.mint_authority.key() == expected_authority,
+                AccessMintError::InvalidMintAuthority
+            );
+            
+            let seed_bytes = access_mint_state.seed.to_le_bytes();
+            let authority_seeds = &[
+                AccessMintState::AUTHORITY_SEED_PREFIX,
+                access_mint_state.creator.as_ref(),
+                access_mint_state.content_id.as_ref(),
+                seed_bytes.as_ref(),
+                &[authority_bump],
+            ];
+            
+            token::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ThawAccount {
+                    account: buyer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &[&authority_seeds[..]],
+            ))?;
+        }
+        
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: buyer_token_account.to_account_info(),
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            ),
+            1, // Burn the single access token
+        )?;
+    }
+    
+    ctx.accounts.access_record.revoked = true;
+    access_mint_state.active_supply = access_mint_state
+        .active_supply
+        .checked_sub(1)
+        .ok_or(AccessMintError::NumericalOverflow)?;
+    
+    msg!("Access revoked from buyer: {}", ctx.accounts.buyer.key());
+    
+    emit_cpi!(AccessRevoked {
+        access_mint_state: ctx.accounts.access_mint_state.key(),
+        mint: ctx.accounts.mint.key(),
+        buyer: ctx.accounts.buyer.key(),
+        access_record: ctx.accounts.access_record.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAccess<'info> {
+    /// The buyer whose access is revoked (owner of the access token)
+    pub buyer: Signer<'info>,
+    
+    /// Authorized minter recorded in the access mint state
+    #[account(
+        constraint = minter.key() == access_mint_state.minter @ AccessMintError::Unauthorized,
+    )]
+    pub minter: Signer<'info>,
+    
+    /// Access mint state PDA (its active supply is decremented)
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The mint account
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint authority PDA (freeze authority, thaws soulbound tokens before burning)
+    /// CHECK: PDA validated manually in instruction
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Buyer's token account (ATA); omitted for `RecordOnly` mints
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Buyer's access record PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessRecord::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump = access_record.bump,
+    )]
+    pub access_record: Account<'info, AccessRecord>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Rotate the authority allowed to mint access tokens (creator only)
+pub fn set_minter(ctx: Context<SetMinter>, new_minter: Pubkey) -> Result<()> {
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    let old_minter = access_mint_state.minter;
+    
+    access_mint_state.minter = new_minter;
+    
+    msg!("Minter rotated from {} to {}", old_minter, new_minter);
+    
+    emit_cpi!(MinterUpdated {
+        access_mint_state: access_mint_state.key(),
+        old_minter,
+        new_minter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMinter<'info> {
+    /// The creator who owns the access mint
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+}
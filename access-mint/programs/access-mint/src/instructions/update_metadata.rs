@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{update_metadata_accounts_v2, Metadata, UpdateMetadataAccountsV2};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Update the name, symbol and uri of the access token metadata (creator only)
+pub fn update_metadata(ctx: Context<UpdateMetadata>, metadata: AccessTokenMetadata) -> Result<()> {
+    metadata.validate()?;
+    
+    let access_mint_state = &ctx.accounts.access_mint_state;
+    let (expected_authority, authority_bump) = access_mint_state.find_mint_authority(ctx.program_id);
+    require!(
+        ctx.accounts.mint_authority.key() == expected_authority,
+        AccessMintError::InvalidMintAuthority
+    );
+    
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        access_mint_state.creator.as_ref(),
+        access_mint_state.content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    
+    // The mint authority PDA is the metadata update authority
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            &[&authority_seeds[..]],
+        ),
+        None,
+        Some(metadata.to_data_v2()),
+        None,
+        None,
+    )?;
+    
+    msg!("Access token metadata updated: name={}, symbol={}, uri={}", 
+        metadata.name, metadata.symbol, metadata.uri);
+    
+    emit_cpi!(MetadataUpdated {
+        access_mint_state: access_mint_state.key(),
+        metadata,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    /// The creator who owns the content
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Mint authority PDA (metadata update authority)
+    /// CHECK: PDA validated manually in instruction
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Metaplex metadata account for the access mint
+    /// CHECK: PDA validated by seeds, updated by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), access_mint_state.mint.as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Metaplex token metadata program
+    pub token_metadata_program: Program<'info, Metadata>,
+}
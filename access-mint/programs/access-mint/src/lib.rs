@@ -6,8 +6,10 @@ declare_id!("FmqUGBhdGHK9iPWbweoBXFBU2BY9g6C5ncfQstbXpDf6");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
 
 use instructions::*;
+use state::{AccessConfig, AccessTokenMetadata};
 
 #[program]
 pub mod access_mint {
@@ -18,17 +20,63 @@ pub mod access_mint {
     /// # Arguments
     /// * `content_id` - 32-byte unique identifier for the content
     /// * `seed` - Seed for PDA derivation (allows multiple mints per content)
+    /// * `minter` - Authority allowed to mint access tokens (e.g. the payment escrow PDA)
+    /// * `metadata` - Name, symbol and uri written to the Metaplex metadata account
+    /// * `config` - Transfer mode (`Frozen` = soulbound, `RecordOnly` = no token),
+    ///   access period per purchase (0 = permanent) and optional max supply
     pub fn initialize_mint(
         ctx: Context<InitializeMint>,
         content_id: [u8; 32],
         seed: u64,
+        minter: Pubkey,
+        metadata: AccessTokenMetadata,
+        config: AccessConfig,
     ) -> Result<()> {
-        instructions::initialize_mint::initialize_mint(ctx, content_id, seed)
+        instructions::initialize_mint::initialize_mint(ctx, content_id, seed, minter, metadata, config)
     }
 
-    /// Mint an access token to a buyer
-    /// Typically called via CPI from payment escrow program
-    pub fn mint_access(ctx: Context<MintAccess>) -> Result<()> {
-        instructions::mint_access::mint_access(ctx)
+    /// Grant access to a buyer: create their access record and mint an access token
+    /// Typically called via CPI from payment escrow program; requires the authorized minter
+    /// Rejected while the platform is paused
+    ///
+    /// # Arguments
+    /// * `escrow` - Escrow that paid for the purchase (recorded in the access record)
+    /// * `price_paid` - Amount paid for the purchase
+    pub fn mint_access(ctx: Context<MintAccess>, escrow: Pubkey, price_paid: u64) -> Result<()> {
+        instructions::mint_access::mint_access(ctx, escrow, price_paid)
+    }
+
+    /// Extend a buyer's access by one access period
+    /// Typically called via CPI from payment escrow program when a renewal is paid
+    pub fn renew_access(ctx: Context<RenewAccess>) -> Result<()> {
+        instructions::renew_access::renew_access(ctx)
+    }
+
+    /// Check whether a buyer's access record is currently valid
+    /// Returns the result so verifiers can simulate or CPI into it
+    pub fn has_valid_access(ctx: Context<HasValidAccess>) -> Result<bool> {
+        instructions::has_valid_access::has_valid_access(ctx)
+    }
+
+    /// Revoke a buyer's access: mark their access record revoked and burn their access token
+    /// Typically called via CPI from payment escrow program when a purchase is refunded
+    pub fn revoke_access(ctx: Context<RevokeAccess>) -> Result<()> {
+        instructions::revoke_access::revoke_access(ctx)
+    }
+
+    /// Rotate the authorized minter (creator only)
+    ///
+    /// # Arguments
+    /// * `new_minter` - New authority allowed to mint access tokens
+    pub fn set_minter(ctx: Context<SetMinter>, new_minter: Pubkey) -> Result<()> {
+        instructions::set_minter::set_minter(ctx, new_minter)
+    }
+
+    /// Update the access token's name, symbol and uri (creator only)
+    ///
+    /// # Arguments
+    /// * `metadata` - New name, symbol and uri
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, metadata: AccessTokenMetadata) -> Result<()> {
+        instructions::update_metadata::update_metadata(ctx, metadata)
     }
 }
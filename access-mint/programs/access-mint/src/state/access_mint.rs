@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::mpl_token_metadata::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+use crate::errors::*;
 
 /// Access Mint State - stores metadata about the access token mint
 #[account]
@@ -15,15 +18,30 @@ pub struct AccessMintState {
     /// Mint authority (should be this PDA)
     pub mint_authority: Pubkey,
     
+    /// Authority allowed to call `mint_access` (e.g. the payment escrow PDA)
+    pub minter: Pubkey,
+    
     /// Seed used for PDA derivation
     pub seed: u64,
     
     /// Total number of access tokens minted
     pub total_minted: u64,
     
+    /// Purchases currently holding an edition: minted and not revoked
+    pub active_supply: u64,
+    
     /// Timestamp when created
     pub created_ts: i64,
     
+    /// Whether holders can transfer their access tokens
+    pub transfer_mode: TransferMode,
+    
+    /// How long each purchase or renewal grants access (0 = permanent)
+    pub access_duration_secs: i64,
+    
+    /// Maximum number of purchases (None = unlimited)
+    pub max_supply: Option<u64>,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -31,12 +49,126 @@ pub struct AccessMintState {
 impl AccessMintState {
     /// Size calculation for account allocation
     /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + Pubkey (32) 
-    /// + Pubkey (32) + u64 (8) + u64 (8) + i64 (8) + u8 (1)
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
+    /// + Pubkey (32) + Pubkey (32) + u64 (8) + u64 (8) + u64 (8) + i64 (8) + TransferMode (1)
+    /// + i64 (8) + Option<u64> (9) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 8 + 9 + 1;
     
     /// PDA seed prefix for access mint state
     pub const SEED_PREFIX: &'static [u8] = b"access_mint_state";
     
     /// PDA seed prefix for mint authority
     pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"access_mint_authority";
+    
+    /// Maximum access duration per purchase (10 years)
+    pub const MAX_ACCESS_DURATION_SECS: i64 = 10 * 365 * 24 * 60 * 60;
+    
+    /// Whether every edition is held by a purchase
+    /// Revoked (refunded) purchases give their edition back, so a sold-out drop can reopen
+    pub fn is_sold_out(&self) -> bool {
+        self.max_supply
+            .is_some_and(|max_supply| self.active_supply >= max_supply)
+    }
+    
+    /// Expiry after granting one access period on top of `current_expiry`
+    /// Unexpired access is extended from its current expiry, lapsed access from `now`
+    /// Returns 0 (never expires) for permanent access mints
+    pub fn extend_expiry(&self, current_expiry: i64, now: i64) -> Result<i64> {
+        if self.access_duration_secs == 0 {
+            return Ok(0);
+        }
+        
+        current_expiry
+            .max(now)
+            .checked_add(self.access_duration_secs)
+            .ok_or(AccessMintError::NumericalOverflow.into())
+    }
+    
+    /// Mint authority PDA and bump for this access mint
+    pub fn find_mint_authority(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::AUTHORITY_SEED_PREFIX,
+                self.creator.as_ref(),
+                self.content_id.as_ref(),
+                self.seed.to_le_bytes().as_ref(),
+            ],
+            program_id,
+        )
+    }
+}
+
+/// How access tokens behave once they reach a buyer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferMode {
+    /// Plain SPL tokens that holders can transfer or sell
+    Transferable,
+    /// Soulbound: each buyer token account is frozen by the mint authority after minting
+    Frozen,
+    /// No token is minted; the buyer's `AccessRecord` is the only proof of access
+    RecordOnly,
+}
+
+/// Issuance options chosen at `initialize_mint`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct AccessConfig {
+    /// Whether holders can transfer their access tokens
+    pub transfer_mode: TransferMode,
+    /// Access period per purchase or renewal (0 = permanent)
+    pub access_duration_secs: i64,
+    /// Maximum number of purchases (None = unlimited)
+    pub max_supply: Option<u64>,
+}
+
+impl AccessConfig {
+    /// Check the access duration and supply cap
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (0..=AccessMintState::MAX_ACCESS_DURATION_SECS).contains(&self.access_duration_secs),
+            AccessMintError::InvalidAccessDuration
+        );
+        require!(
+            self.max_supply != Some(0),
+            AccessMintError::InvalidMaxSupply
+        );
+        
+        Ok(())
+    }
+}
+
+/// Display metadata written to the access token's Metaplex metadata account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AccessTokenMetadata {
+    /// Token name shown by wallets (max 32 bytes)
+    pub name: String,
+    /// Token symbol (max 10 bytes)
+    pub symbol: String,
+    /// URI of the off-chain JSON (image, description) (max 200 bytes)
+    pub uri: String,
+}
+
+impl AccessTokenMetadata {
+    /// Check field lengths against the Metaplex limits
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.name.len() <= MAX_NAME_LENGTH
+                && self.symbol.len() <= MAX_SYMBOL_LENGTH
+                && self.uri.len() <= MAX_URI_LENGTH,
+            AccessMintError::MetadataTooLong
+        );
+        
+        Ok(())
+    }
+    
+    /// Metaplex data for this metadata (no creators, no royalties)
+    pub fn to_data_v2(&self) -> DataV2 {
+        DataV2 {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
 }
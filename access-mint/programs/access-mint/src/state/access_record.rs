@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// Access Record - on-chain proof of a buyer's purchase for an access mint
+/// Created by `mint_access` for every access mint; for `RecordOnly` mints it is the only proof of access
+#[account]
+pub struct AccessRecord {
+    /// Access mint state this record belongs to
+    pub access_mint_state: Pubkey,
+    
+    /// Buyer holding the access
+    pub buyer: Pubkey,
+    
+    /// Escrow that paid for the purchase (default pubkey when minted directly)
+    pub escrow: Pubkey,
+    
+    /// Amount paid for the purchase, in the listing's currency
+    pub price_paid: u64,
+    
+    /// Timestamp of the purchase
+    pub purchased_ts: i64,
+    
+    /// Edition number of the purchase (1-based, in mint order)
+    pub edition: u64,
+    
+    /// Timestamp when access expires (0 = never)
+    pub expires_at: i64,
+    
+    /// Whether access was revoked (e.g. the purchase was refunded)
+    pub revoked: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccessRecord {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + u64 (8)
+    /// + i64 (8) + u64 (8) + i64 (8) + bool (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+    
+    /// PDA seed prefix for access records
+    pub const SEED_PREFIX: &'static [u8] = b"access_record";
+    
+    /// Whether the record grants access at `now`
+    pub fn is_valid(&self, now: i64) -> bool {
+        !self.revoked && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
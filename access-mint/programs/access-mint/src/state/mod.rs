@@ -1,3 +1,5 @@
 pub mod access_mint;
+pub mod access_record;
 
 pub use access_mint::*;
+pub use access_record::*;
@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AccessMint } from "../target/types/access_mint";
+import { Distribution } from "../../distribution/target/types/distribution";
 import {
   PublicKey,
   SystemProgram,
@@ -12,6 +13,8 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   getAccount,
+  getOrCreateAssociatedTokenAccount,
+  transfer,
 } from "@solana/spl-token";
 import { expect } from "chai";
 
@@ -20,12 +23,58 @@ describe("Access Mint Program", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.AccessMint as Program<AccessMint>;
+  const distributionProgram = new Program<Distribution>(
+    require("../../distribution/target/idl/distribution.json"),
+    provider
+  );
 
   let creator: Keypair;
   let buyer: Keypair;
   let mint: Keypair;
 
   const contentId = Array.from({ length: 32 }, (_, i) => i + 1);
+
+  const accessTokenMetadata = {
+    name: "Premium Course Access",
+    symbol: "ACCESS",
+    uri: "https://example.com/access/1.json",
+  };
+
+  // Metaplex token metadata program, loaded from tests/fixtures (see Anchor.toml)
+  const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+  const findAccessRecordPda = (accessMintState: PublicKey, owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("access_record"), accessMintState.toBuffer(), owner.toBuffer()],
+      program.programId
+    )[0];
+  const findMetadataPda = (mintKey: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mintKey.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+  // Distribution platform config holding the global pause flag checked by mint_access
+  const [platformConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    distributionProgram.programId
+  );
+  // Events are emitted with `emit_cpi!`: each is a self-invoked inner instruction whose data
+  // is the 8-byte event instruction tag followed by the encoded event
+  const fetchCpiEvents = async (signature: string) => {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const accountKeys = tx.transaction.message.getAccountKeys();
+
+    return (tx.meta?.innerInstructions ?? [])
+      .flatMap((inner) => inner.instructions)
+      .filter((ix) => accountKeys.get(ix.programIdIndex)?.equals(program.programId))
+      .map((ix) => {
+        const data = Buffer.from(anchor.utils.bytes.bs58.decode(ix.data)).subarray(8);
+        return program.coder.events.decode(data.toString("base64"));
+      })
+      .filter((event) => event !== null);
+  };
   const seed = new anchor.BN(1);
 
   before(async () => {
@@ -40,6 +89,29 @@ describe("Access Mint Program", () => {
     );
     await provider.connection.confirmTransaction(airdropSig);
 
+    // mint_access reads the pause flag from the platform config; create it if this cluster has none
+    if (!(await provider.connection.getAccountInfo(platformConfigPda))) {
+      const [programDataPda] = PublicKey.findProgramAddressSync(
+        [distributionProgram.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+      await distributionProgram.methods
+        .initializePlatformConfig({
+          treasury: Keypair.generate().publicKey,
+          minPlatformFeeBps: 100,
+          maxPlatformFeeBps: 1000,
+          maxCollaborators: 10,
+        })
+        .accountsPartial({
+          admin: creator.publicKey,
+          platformConfig: platformConfigPda,
+          distributionProgram: distributionProgram.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
     console.log("Test accounts initialized");
     console.log("Program ID:", program.programId.toString());
   });
@@ -71,12 +143,14 @@ describe("Access Mint Program", () => {
       );
 
       const tx = await program.methods
-        .initializeMint(contentId, seed)
+        .initializeMint(contentId, seed, creator.publicKey, accessTokenMetadata, { transferMode: { transferable: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: null })
         .accountsPartial({
           creator: creator.publicKey,
           accessMintState: accessMintStatePda,
           mint: mint.publicKey,
           mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(mint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -93,6 +167,7 @@ describe("Access Mint Program", () => {
       expect(accessMintState.creator.toString()).to.equal(creator.publicKey.toString());
       expect(accessMintState.mint.toString()).to.equal(mint.publicKey.toString());
       expect(accessMintState.mintAuthority.toString()).to.equal(mintAuthorityPda.toString());
+      expect(accessMintState.minter.toString()).to.equal(creator.publicKey.toString());
       expect(accessMintState.totalMinted.toNumber()).to.equal(0);
 
       console.log("Mint:", mint.publicKey.toString());
@@ -138,12 +213,14 @@ describe("Access Mint Program", () => {
 
       // Initialize the mint first
       await program.methods
-        .initializeMint(contentId, seed2)
+        .initializeMint(contentId, seed2, creator.publicKey, accessTokenMetadata, { transferMode: { transferable: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: null })
         .accountsPartial({
           creator: creator.publicKey,
           accessMintState: accessMintStatePda,
           mint: mint2.publicKey,
           mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(mint2.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -162,14 +239,17 @@ describe("Access Mint Program", () => {
 
     it("Should mint access token to buyer", async () => {
       const tx = await program.methods
-        .mintAccess()
+        .mintAccess(PublicKey.default, new anchor.BN(0))
         .accountsPartial({
           buyer: buyer.publicKey,
           payer: buyer.publicKey,
+          minter: creator.publicKey,
           accessMintState: accessMintStatePda,
+          platformConfig: platformConfigPda,
           mint: mint.publicKey,
           mintAuthority: mintAuthorityPda,
           buyerTokenAccount: buyerTokenAccount,
+          accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -200,6 +280,103 @@ describe("Access Mint Program", () => {
       console.log("Total minted:", accessMintState.totalMinted.toNumber());
     });
 
+    it("Should record the purchase in an access record", async () => {
+      const record = await program.account.accessRecord.fetch(
+        findAccessRecordPda(accessMintStatePda, buyer.publicKey)
+      );
+
+      expect(record.buyer.toString()).to.equal(buyer.publicKey.toString());
+      expect(record.accessMintState.toString()).to.equal(accessMintStatePda.toString());
+      expect(record.expiresAt.toNumber()).to.equal(0);
+      expect(record.revoked).to.be.false;
+      expect(record.purchasedTs.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Should reject a second purchase by the same wallet", async () => {
+      try {
+        await program.methods
+          .mintAccess(PublicKey.default, new anchor.BN(0))
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            payer: buyer.publicKey,
+            minter: creator.publicKey,
+            accessMintState: accessMintStatePda,
+            platformConfig: platformConfigPda,
+            mint: mint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            buyerTokenAccount: buyerTokenAccount,
+            accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+        expect.fail("Should have thrown AlreadyMinted error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("AlreadyMinted");
+        console.log("Correctly rejected duplicate purchase");
+      }
+    });
+
+    it("Should reject minting by an unauthorized signer", async () => {
+      try {
+        await program.methods
+          .mintAccess(PublicKey.default, new anchor.BN(0))
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            payer: buyer.publicKey,
+            minter: buyer.publicKey,
+            accessMintState: accessMintStatePda,
+            platformConfig: platformConfigPda,
+            mint: mint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            buyerTokenAccount: buyerTokenAccount,
+            accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+        console.log("Correctly rejected mint from unauthorized signer");
+      }
+    });
+
+    it("Should let the creator rotate the minter", async () => {
+      const newMinter = Keypair.generate().publicKey;
+
+      await program.methods
+        .setMinter(newMinter)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
+      let accessMintState = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(accessMintState.minter.toString()).to.equal(newMinter.toString());
+
+      // Restore the original minter for the remaining tests
+      await program.methods
+        .setMinter(creator.publicKey)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
+      accessMintState = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(accessMintState.minter.toString()).to.equal(creator.publicKey.toString());
+
+      console.log("Minter rotated by creator");
+    });
+
     it("Should verify buyer has access", async () => {
       // Check token balance
       const tokenAccountInfo = await getAccount(
@@ -215,6 +392,649 @@ describe("Access Mint Program", () => {
     });
   });
 
+  describe("Access Token Metadata", () => {
+    const accessMintStateFor = (seedValue: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seedValue.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    const mintAuthorityFor = (seedValue: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seedValue.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+
+    // Metaplex layout: key (1) + update authority (32) + mint (32) + name (4-byte length + bytes)
+    const readMetadataName = async (mintKey: PublicKey) => {
+      const info = await provider.connection.getAccountInfo(findMetadataPda(mintKey));
+      const length = info!.data.readUInt32LE(65);
+      return info!.data.subarray(69, 69 + length).toString("utf8").replace(/\0/g, "");
+    };
+
+    it("Should write Metaplex metadata on initialize", async () => {
+      const name = await readMetadataName(mint.publicKey);
+      expect(name).to.equal(accessTokenMetadata.name);
+
+      console.log("Metadata name:", name);
+    });
+
+    it("Should let the creator update metadata", async () => {
+      // `mint` holds the seed 2 mint after the Mint Access Token suite
+      const seed2 = new anchor.BN(2);
+
+      await program.methods
+        .updateMetadata({ ...accessTokenMetadata, name: "Premium Course Access v2" })
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStateFor(seed2),
+          mintAuthority: mintAuthorityFor(seed2),
+          metadata: findMetadataPda(mint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        })
+        .rpc();
+
+      const name = await readMetadataName(mint.publicKey);
+      expect(name).to.equal("Premium Course Access v2");
+
+      console.log("Metadata updated by creator");
+    });
+
+    it("Should reject metadata updates from non-creators", async () => {
+      const seed2 = new anchor.BN(2);
+
+      try {
+        await program.methods
+          .updateMetadata(accessTokenMetadata)
+          .accountsPartial({
+            creator: buyer.publicKey,
+            accessMintState: accessMintStateFor(seed2),
+            mintAuthority: mintAuthorityFor(seed2),
+            metadata: findMetadataPda(mint.publicKey),
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          })
+          .signers([buyer])
+          .rpc();
+
+        expect.fail("Should have thrown InvalidCreator error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidCreator");
+        console.log("Correctly rejected metadata update from non-creator");
+      }
+    });
+
+    it("Should reject metadata that exceeds Metaplex limits", async () => {
+      const seed2 = new anchor.BN(2);
+
+      try {
+        await program.methods
+          .updateMetadata({ ...accessTokenMetadata, symbol: "WAY_TOO_LONG_SYMBOL" })
+          .accountsPartial({
+            creator: creator.publicKey,
+            accessMintState: accessMintStateFor(seed2),
+            mintAuthority: mintAuthorityFor(seed2),
+            metadata: findMetadataPda(mint.publicKey),
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown MetadataTooLong error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MetadataTooLong");
+        console.log("Correctly rejected oversized metadata");
+      }
+    });
+  });
+
+  describe("Soulbound Access Tokens", () => {
+    const seed3 = new anchor.BN(3);
+    const frozenMint = Keypair.generate();
+    let accessMintStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+    let buyerTokenAccount: PublicKey;
+
+    before(async () => {
+      [accessMintStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed3.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed3.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeMint(contentId, seed3, creator.publicKey, accessTokenMetadata, { transferMode: { frozen: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: null })
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: frozenMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(frozenMint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([frozenMint])
+        .rpc();
+
+      buyerTokenAccount = await getAssociatedTokenAddress(
+        frozenMint.publicKey,
+        buyer.publicKey
+      );
+    });
+
+    it("Should record the transfer mode", async () => {
+      const accessMintState = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(accessMintState.transferMode).to.deep.equal({ frozen: {} });
+    });
+
+    it("Should freeze the buyer account after minting", async () => {
+      await program.methods
+        .mintAccess(PublicKey.default, new anchor.BN(0))
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          platformConfig: platformConfigPda,
+          mint: frozenMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: buyerTokenAccount,
+          accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(provider.connection, buyerTokenAccount);
+      expect(tokenAccountInfo.amount).to.equal(BigInt(1));
+      expect(tokenAccountInfo.isFrozen).to.be.true;
+
+      console.log("Soulbound access token minted and frozen");
+    });
+
+    it("Should prevent the buyer from transferring the access token", async () => {
+      const other = Keypair.generate();
+      const otherTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        creator,
+        frozenMint.publicKey,
+        other.publicKey
+      );
+
+      try {
+        await transfer(
+          provider.connection,
+          buyer,
+          buyerTokenAccount,
+          otherTokenAccount.address,
+          buyer,
+          1
+        );
+
+        expect.fail("Transfer of a frozen access token should fail");
+      } catch (error: any) {
+        expect(error.toString()).to.not.include("Transfer of a frozen access token should fail");
+        console.log("Correctly blocked transfer of soulbound token");
+      }
+    });
+
+    it("Should thaw and burn the token on revoke", async () => {
+      await program.methods
+        .revokeAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: frozenMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: buyerTokenAccount,
+          accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(provider.connection, buyerTokenAccount);
+      expect(tokenAccountInfo.amount).to.equal(BigInt(0));
+
+      console.log("Soulbound access token revoked");
+    });
+  });
+
+  describe("Time-Limited Access", () => {
+    const seed4 = new anchor.BN(4);
+    const subscriptionMint = Keypair.generate();
+    const monthSecs = 30 * 24 * 60 * 60;
+    let accessMintStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+    let accessRecordPda: PublicKey;
+
+    const accessCheckAccounts = () => ({
+      buyer: buyer.publicKey,
+      accessMintState: accessMintStatePda,
+      accessRecord: accessRecordPda,
+    });
+
+    before(async () => {
+      [accessMintStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed4.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed4.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      accessRecordPda = findAccessRecordPda(accessMintStatePda, buyer.publicKey);
+
+      await program.methods
+        .initializeMint(contentId, seed4, creator.publicKey, accessTokenMetadata, { transferMode: { transferable: {} }, accessDurationSecs: new anchor.BN(monthSecs), maxSupply: null })
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: subscriptionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(subscriptionMint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([subscriptionMint])
+        .rpc();
+    });
+
+    it("Should report no access before purchase", async () => {
+      const valid = await program.methods
+        .hasValidAccess()
+        .accountsPartial(accessCheckAccounts())
+        .view();
+
+      expect(valid).to.be.false;
+    });
+
+    it("Should grant access for one period on mint", async () => {
+      await program.methods
+        .mintAccess(PublicKey.default, new anchor.BN(0))
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          platformConfig: platformConfigPda,
+          mint: subscriptionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: await getAssociatedTokenAddress(subscriptionMint.publicKey, buyer.publicKey),
+          accessRecord: accessRecordPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const record = await program.account.accessRecord.fetch(accessRecordPda);
+      const now = Math.floor(Date.now() / 1000);
+      expect(record.expiresAt.toNumber()).to.be.greaterThan(now + monthSecs - 60);
+
+      const valid = await program.methods
+        .hasValidAccess()
+        .accountsPartial(accessCheckAccounts())
+        .view();
+      expect(valid).to.be.true;
+
+      console.log("Access expires at:", record.expiresAt.toNumber());
+    });
+
+    it("Should extend an active period on renewal", async () => {
+      const before = await program.account.accessRecord.fetch(accessRecordPda);
+
+      await program.methods
+        .renewAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          accessRecord: accessRecordPda,
+        })
+        .rpc();
+
+      const after = await program.account.accessRecord.fetch(accessRecordPda);
+      expect(after.expiresAt.toNumber()).to.equal(before.expiresAt.toNumber() + monthSecs);
+
+      console.log("Access renewed until:", after.expiresAt.toNumber());
+    });
+
+    it("Should reject renewal by an unauthorized signer", async () => {
+      try {
+        await program.methods
+          .renewAccess()
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            minter: buyer.publicKey,
+            accessMintState: accessMintStatePda,
+            accessRecord: accessRecordPda,
+          })
+          .signers([buyer])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+        console.log("Correctly rejected unauthorized renewal");
+      }
+    });
+
+    it("Should end access on revoke", async () => {
+      await program.methods
+        .revokeAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: subscriptionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: await getAssociatedTokenAddress(subscriptionMint.publicKey, buyer.publicKey),
+          accessRecord: accessRecordPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const valid = await program.methods
+        .hasValidAccess()
+        .accountsPartial(accessCheckAccounts())
+        .view();
+      expect(valid).to.be.false;
+
+      console.log("Access ended by revoke");
+    });
+  });
+
+  describe("Record-Only Access", () => {
+    const seed5 = new anchor.BN(5);
+    const recordMint = Keypair.generate();
+    const escrowKey = Keypair.generate().publicKey;
+    let accessMintStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+    let accessRecordPda: PublicKey;
+
+    before(async () => {
+      [accessMintStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed5.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed5.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      accessRecordPda = findAccessRecordPda(accessMintStatePda, buyer.publicKey);
+
+      await program.methods
+        .initializeMint(contentId, seed5, creator.publicKey, accessTokenMetadata, { transferMode: { recordOnly: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: null })
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: recordMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(recordMint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([recordMint])
+        .rpc();
+    });
+
+    it("Should grant access with a record and no token", async () => {
+      const price = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+      const tx = await program.methods
+        .mintAccess(escrowKey, price)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          platformConfig: platformConfigPda,
+          mint: recordMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: null,
+          accessRecord: accessRecordPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc({ commitment: "confirmed" });
+
+      const record = await program.account.accessRecord.fetch(accessRecordPda);
+      expect(record.escrow.toString()).to.equal(escrowKey.toString());
+      expect(record.pricePaid.toNumber()).to.equal(price.toNumber());
+
+      const [event] = await fetchCpiEvents(tx);
+      expect(event.name).to.equal("accessMinted");
+      expect(event.data.buyer.toString()).to.equal(buyer.publicKey.toString());
+      expect(event.data.escrow.toString()).to.equal(escrowKey.toString());
+      expect(event.data.pricePaid.toNumber()).to.equal(price.toNumber());
+      expect(event.data.edition.toNumber()).to.equal(record.edition.toNumber());
+
+      const ata = await getAssociatedTokenAddress(recordMint.publicKey, buyer.publicKey);
+      expect(await provider.connection.getAccountInfo(ata)).to.be.null;
+
+      console.log("Record-only access granted without a token account");
+    });
+
+    it("Should mark the record revoked on revoke", async () => {
+      await program.methods
+        .revokeAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: recordMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: null,
+          accessRecord: accessRecordPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const record = await program.account.accessRecord.fetch(accessRecordPda);
+      expect(record.revoked).to.be.true;
+
+      const valid = await program.methods
+        .hasValidAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          accessMintState: accessMintStatePda,
+          accessRecord: accessRecordPda,
+        })
+        .view();
+      expect(valid).to.be.false;
+
+      console.log("Record-only access revoked");
+    });
+  });
+
+  describe("Limited Editions", () => {
+    const seed6 = new anchor.BN(6);
+    const editionMint = Keypair.generate();
+    const lateBuyer = Keypair.generate();
+    let accessMintStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+
+    const mintEditionTo = async (owner: Keypair) =>
+      program.methods
+        .mintAccess(PublicKey.default, new anchor.BN(0))
+        .accountsPartial({
+          buyer: owner.publicKey,
+          payer: owner.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          platformConfig: platformConfigPda,
+          mint: editionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: await getAssociatedTokenAddress(editionMint.publicKey, owner.publicKey),
+          accessRecord: findAccessRecordPda(accessMintStatePda, owner.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+    before(async () => {
+      [accessMintStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed6.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed6.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeMint(contentId, seed6, creator.publicKey, accessTokenMetadata, { transferMode: { transferable: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: new anchor.BN(1) })
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: editionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          metadata: findMetadataPda(editionMint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([editionMint])
+        .rpc();
+    });
+
+    it("Should record the edition number of each purchase", async () => {
+      await mintEditionTo(buyer);
+
+      const record = await program.account.accessRecord.fetch(
+        findAccessRecordPda(accessMintStatePda, buyer.publicKey)
+      );
+      expect(record.edition.toNumber()).to.equal(1);
+
+      console.log("Buyer received edition", record.edition.toNumber());
+    });
+
+    it("Should refuse to mint past max supply", async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        lateBuyer.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      try {
+        await mintEditionTo(lateBuyer);
+
+        expect.fail("Should have thrown SoldOut error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("SoldOut");
+        console.log("Correctly refused mint once sold out");
+      }
+    });
+
+    it("Should give the edition back when a purchase is revoked", async () => {
+      await program.methods
+        .revokeAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          minter: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: editionMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: await getAssociatedTokenAddress(editionMint.publicKey, buyer.publicKey),
+          accessRecord: findAccessRecordPda(accessMintStatePda, buyer.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+      let state = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(state.activeSupply.toNumber()).to.equal(0);
+
+      await mintEditionTo(lateBuyer);
+
+      // The freed edition is sold again under a new edition number
+      const record = await program.account.accessRecord.fetch(
+        findAccessRecordPda(accessMintStatePda, lateBuyer.publicKey)
+      );
+      expect(record.edition.toNumber()).to.equal(2);
+
+      state = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(state.totalMinted.toNumber()).to.equal(2);
+      expect(state.activeSupply.toNumber()).to.equal(1);
+
+      console.log("Revoked edition resold as edition", record.edition.toNumber());
+    });
+  });
+
   describe("Access Token Properties", () => {
     it("Should verify mint has 0 decimals", async () => {
       const mintInfo = await provider.connection.getAccountInfo(mint.publicKey);
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::Pubkey;
+use clap::ValueEnum;
+use ownmark_sdk::access_mint::state::TransferMode;
+use ownmark_sdk::distribution::state::{CollaboratorShare, DustPolicy};
+use std::str::FromStr;
+
+/// Parse a content identifier given as 64 hex characters
+pub fn parse_content_id(value: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(value).map_err(|err| format!("content id must be hex: {err}"))?;
+    
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("content id must be 32 bytes, got {}", bytes.len()))
+}
+
+/// Parse a collaborator share given as `PUBKEY:BPS`
+pub fn parse_collaborator(value: &str) -> Result<CollaboratorShare, String> {
+    let (pubkey, share_bps) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected PUBKEY:BPS, got {value}"))?;
+    
+    Ok(CollaboratorShare {
+        pubkey: Pubkey::from_str(pubkey).map_err(|err| format!("invalid collaborator {pubkey}: {err}"))?,
+        share_bps: share_bps
+            .parse()
+            .map_err(|err| format!("invalid share {share_bps}: {err}"))?,
+    })
+}
+
+/// Command-line form of [`DustPolicy`]
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum DustPolicyArg {
+    /// The creator receives the remainder
+    Creator,
+    /// The recipient with the largest share receives the remainder
+    LargestShare,
+    /// The remainder is carried into the next distribution
+    CarryForward,
+}
+
+impl From<DustPolicyArg> for DustPolicy {
+    fn from(policy: DustPolicyArg) -> Self {
+        match policy {
+            DustPolicyArg::Creator => DustPolicy::Creator,
+            DustPolicyArg::LargestShare => DustPolicy::LargestShare,
+            DustPolicyArg::CarryForward => DustPolicy::CarryForward,
+        }
+    }
+}
+
+/// Command-line form of [`TransferMode`]
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum TransferModeArg {
+    /// Plain SPL tokens that holders can transfer or sell
+    Transferable,
+    /// Soulbound tokens frozen in the buyer's account
+    Frozen,
+    /// No token; the access record is the only proof of access
+    RecordOnly,
+}
+
+impl From<TransferModeArg> for TransferMode {
+    fn from(mode: TransferModeArg) -> Self {
+        match mode {
+            TransferModeArg::Transferable => TransferMode::Transferable,
+            TransferModeArg::Frozen => TransferMode::Frozen,
+            TransferModeArg::RecordOnly => TransferMode::RecordOnly,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn content_id_must_be_32_hex_bytes() {
+        assert_eq!(parse_content_id(&"ab".repeat(32)), Ok([0xab; 32]));
+        assert!(parse_content_id(&"ab".repeat(31)).is_err());
+        assert!(parse_content_id(&"zz".repeat(32)).is_err());
+    }
+    
+    #[test]
+    fn collaborator_is_pubkey_and_share() {
+        let pubkey = Pubkey::new_unique();
+        
+        let share = parse_collaborator(&format!("{pubkey}:1500")).unwrap();
+        assert_eq!(share.pubkey, pubkey);
+        assert_eq!(share.share_bps, 1500);
+        
+        assert!(parse_collaborator(&pubkey.to_string()).is_err());
+        assert!(parse_collaborator(&format!("{pubkey}:-1")).is_err());
+        assert!(parse_collaborator("not-a-key:100").is_err());
+    }
+}
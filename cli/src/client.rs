@@ -0,0 +1,164 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ownmark_sdk::error::decode_instruction_error;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_client::client_error::ClientError;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+/// RPC connection plus the keypair that signs and pays for transactions
+pub struct Client {
+    /// Connection to the cluster, at `confirmed` commitment
+    pub rpc: RpcClient,
+    
+    /// Keypair file, read on first use so read-only commands work without one
+    keypair_path: String,
+}
+
+impl Client {
+    pub fn new(url: &str, keypair_path: &str) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()),
+            keypair_path: expand_home(keypair_path),
+        }
+    }
+    
+    /// Keypair from `--keypair`
+    pub fn payer(&self) -> Result<Keypair> {
+        read_keypair_file(&self.keypair_path)
+            .map_err(|err| anyhow!("failed to read keypair {}: {err}", self.keypair_path))
+    }
+    
+    /// Fetch and decode a program account
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        self.maybe_account(address)?
+            .with_context(|| format!("account {address} does not exist"))
+    }
+    
+    /// Fetch and decode a program account that may not exist
+    pub fn maybe_account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())
+            .with_context(|| format!("failed to fetch account {address}"))?
+            .value;
+        
+        account
+            .map(|account| {
+                ownmark_sdk::accounts::decode(&account.data)
+                    .map_err(|err| anyhow!("failed to decode account {address}: {err}"))
+            })
+            .transpose()
+    }
+    
+    /// Owner program of an account, e.g. the token program of a mint
+    pub fn owner(&self, address: &Pubkey) -> Result<Pubkey> {
+        let account = self
+            .rpc
+            .get_account(address)
+            .with_context(|| format!("failed to fetch account {address}"))?;
+        Ok(account.owner)
+    }
+    
+    /// Fetch and decode every `T` account of `program_id` matching `filters`
+    /// The account discriminator is always added as the first filter
+    pub fn program_accounts<T: AccountDeserialize + Discriminator>(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, T)>> {
+        let mut all_filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, T::DISCRIMINATOR))];
+        all_filters.extend(filters);
+        
+        let config = RpcProgramAccountsConfig {
+            filters: Some(all_filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        
+        self.rpc
+            .get_program_accounts_with_config(program_id, config)
+            .context("failed to fetch program accounts")?
+            .into_iter()
+            .map(|(address, account)| {
+                let decoded = ownmark_sdk::accounts::decode(&account.data)
+                    .map_err(|err| anyhow!("failed to decode account {address}: {err}"))?;
+                Ok((address, decoded))
+            })
+            .collect()
+    }
+    
+    /// Sign `instructions` with the payer and `signers`, send them in one transaction and wait for confirmation
+    pub fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<Signature> {
+        let payer = self.payer()?;
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+        
+        let blockhash = self.rpc.get_latest_blockhash().context("failed to fetch blockhash")?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+        
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|err| program_error(instructions, err))
+    }
+    
+    /// Simulate `instruction` and return the data it returned (empty if it returned nothing)
+    /// Signatures are not verified, so the payer keypair only provides the fee payer address
+    pub fn simulate(&self, instruction: Instruction) -> Result<Vec<u8>> {
+        let payer = self.payer()?.pubkey();
+        let transaction = Transaction::new_with_payer(&[instruction], Some(&payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        
+        let result = self
+            .rpc
+            .simulate_transaction_with_config(&transaction, config)
+            .context("failed to simulate transaction")?
+            .value;
+        if let Some(err) = result.err {
+            bail!("simulation failed: {err:?}");
+        }
+        
+        match result.return_data {
+            Some(return_data) => STANDARD
+                .decode(return_data.data.0)
+                .context("invalid return data"),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Report a failed transaction by program error name when one of our programs rejected it
+fn program_error(instructions: &[Instruction], err: ClientError) -> anyhow::Error {
+    if let Some(TransactionError::InstructionError(index, error)) = err.get_transaction_error() {
+        let decoded = instructions
+            .get(index as usize)
+            .and_then(|instruction| decode_instruction_error(&instruction.program_id, &error));
+        if let Some(decoded) = decoded {
+            return anyhow!("instruction {index} failed with {decoded}");
+        }
+    }
+    err.into()
+}
+
+/// Expand a leading `~/` the way the Solana CLI does for keypair paths
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
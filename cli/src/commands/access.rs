@@ -0,0 +1,64 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::{ArgGroup, Subcommand};
+use ownmark_sdk::accounts::{AccessRecord, Listing};
+use ownmark_sdk::{instructions, pda};
+use crate::client::Client;
+
+#[derive(Subcommand)]
+pub enum AccessCommand {
+    /// Check whether a wallet currently has access, as the access mint program decides it
+    #[command(group(ArgGroup::new("product").required(true).args(["listing", "access_mint_state"])))]
+    Check {
+        /// Wallet to check
+        wallet: Pubkey,
+        
+        /// Listing of the product
+        #[arg(long)]
+        listing: Option<Pubkey>,
+        
+        /// Access mint state of the product
+        #[arg(long)]
+        access_mint_state: Option<Pubkey>,
+    },
+}
+
+impl AccessCommand {
+    pub fn run(self, client: &Client) -> Result<()> {
+        match self {
+            AccessCommand::Check {
+                wallet,
+                listing,
+                access_mint_state,
+            } => {
+                let access_mint_state = match (access_mint_state, listing) {
+                    (Some(access_mint_state), _) => access_mint_state,
+                    (None, Some(listing)) => client.account::<Listing>(&listing)?.access_mint_state,
+                    (None, None) => unreachable!("clap requires --listing or --access-mint-state"),
+                };
+                check(client, &wallet, &access_mint_state)
+            }
+        }
+    }
+}
+
+fn check(client: &Client, wallet: &Pubkey, access_mint_state: &Pubkey) -> Result<()> {
+    // has_valid_access returns a borsh bool; trailing zero bytes may be trimmed from return data
+    let return_data = client.simulate(instructions::has_valid_access(*wallet, *access_mint_state))?;
+    let valid = return_data.first() == Some(&1);
+    
+    println!("Wallet:       {wallet}");
+    println!("Access:       {}", if valid { "valid" } else { "none" });
+    
+    let record: Option<AccessRecord> = client.maybe_account(&pda::access_record(access_mint_state, wallet).0)?;
+    if let Some(record) = record {
+        println!("Edition:      {}", record.edition);
+        println!("Purchased at: {}", record.purchased_ts);
+        println!(
+            "Expires at:   {}",
+            if record.expires_at == 0 { "never".to_string() } else { record.expires_at.to_string() }
+        );
+        println!("Revoked:      {}", record.revoked);
+    }
+    Ok(())
+}
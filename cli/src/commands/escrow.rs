@@ -0,0 +1,149 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use ownmark_sdk::accounts::{AccessMintState, EscrowState, EscrowStatus, Listing};
+use ownmark_sdk::{instructions, Purchase};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::signature::Signer;
+use crate::client::Client;
+use super::{mint_label, payment_mode, purchase_for_escrow};
+
+/// Offsets of the filterable escrow fields, after the 8-byte discriminator
+const BUYER_OFFSET: usize = 8;
+const CREATOR_OFFSET: usize = BUYER_OFFSET + 32;
+const LISTING_OFFSET: usize = CREATOR_OFFSET + 32;
+
+#[derive(Subcommand)]
+pub enum EscrowCommand {
+    /// List escrows, by default those paying the keypair as creator
+    List(ListArgs),
+    
+    /// Open an escrow against a listing with the keypair as buyer
+    Open {
+        /// Listing address
+        listing: Pubkey,
+        
+        /// Escrow seed; use a new one for each purchase of the same content
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    
+    /// Cancel an unsettled escrow and refund the buyer; the keypair must be the buyer or creator
+    Cancel {
+        /// Escrow state address
+        escrow: Pubkey,
+    },
+}
+
+#[derive(Args)]
+pub struct ListArgs {
+    /// Only escrows opened by this buyer
+    #[arg(long)]
+    buyer: Option<Pubkey>,
+    
+    /// Only escrows paying this creator
+    #[arg(long)]
+    creator: Option<Pubkey>,
+    
+    /// Only escrows against this listing
+    #[arg(long)]
+    listing: Option<Pubkey>,
+}
+
+impl EscrowCommand {
+    pub fn run(self, client: &Client) -> Result<()> {
+        match self {
+            EscrowCommand::List(args) => list(client, args),
+            EscrowCommand::Open { listing, seed } => open(client, &listing, seed),
+            EscrowCommand::Cancel { escrow } => cancel(client, &escrow),
+        }
+    }
+}
+
+fn list(client: &Client, args: ListArgs) -> Result<()> {
+    let mut filters = Vec::new();
+    for (offset, value) in [
+        (BUYER_OFFSET, args.buyer),
+        (CREATOR_OFFSET, args.creator),
+        (LISTING_OFFSET, args.listing),
+    ] {
+        if let Some(value) = value {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, value.as_ref())));
+        }
+    }
+    if filters.is_empty() {
+        let creator = client.payer()?.pubkey();
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(CREATOR_OFFSET, creator.as_ref())));
+    }
+    
+    let mut escrows = client.program_accounts::<EscrowState>(&ownmark_sdk::payment_escrow::ID, filters)?;
+    escrows.sort_by_key(|(_, escrow)| escrow.created_ts);
+    
+    println!(
+        "{:<44}  {:<44}  {:<10}  {:>20}  {:<44}",
+        "ESCROW", "BUYER", "STATUS", "PRICE", "CURRENCY"
+    );
+    for (address, escrow) in &escrows {
+        println!(
+            "{:<44}  {:<44}  {:<10}  {:>20}  {:<44}",
+            address,
+            escrow.buyer,
+            status_label(escrow.status),
+            escrow.price,
+            mint_label(escrow.payment_token_mint)
+        );
+    }
+    println!("{} escrow(s)", escrows.len());
+    Ok(())
+}
+
+/// Initialize the escrow and, for SPL listings, its vault's token account in one transaction
+fn open(client: &Client, listing_key: &Pubkey, seed: u64) -> Result<()> {
+    let buyer = client.payer()?.pubkey();
+    let listing: Listing = client.account(listing_key)?;
+    let access_mint_state: AccessMintState = client.account(&listing.access_mint_state)?;
+    let payment = payment_mode(client, listing.payment_token_mint)?;
+    let purchase = Purchase::new(
+        buyer,
+        seed,
+        *listing_key,
+        &listing,
+        &access_mint_state,
+        payment.token_program(),
+    );
+    
+    let mut transaction = vec![instructions::initialize_escrow(&purchase)];
+    // SPL escrows hold the payment in the vault's associated token account
+    transaction.extend(instructions::create_token_account(buyer, purchase.vault(), payment));
+    let signature = client.send(&transaction, &[])?;
+    
+    println!("Escrow:    {}", purchase.escrow());
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+fn cancel(client: &Client, address: &Pubkey) -> Result<()> {
+    let escrow: EscrowState = client.account(address)?;
+    if !matches!(escrow.status, EscrowStatus::Initialized | EscrowStatus::Funded) {
+        bail!("escrow {address} is {} and can no longer be cancelled", status_label(escrow.status));
+    }
+    
+    let purchase = purchase_for_escrow(client, &escrow)?;
+    let authority = client.payer()?.pubkey();
+    let signature = client.send(&[instructions::cancel_escrow(authority, &purchase)], &[])?;
+    
+    println!("Cancelled escrow {address}");
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+fn status_label(status: EscrowStatus) -> &'static str {
+    match status {
+        EscrowStatus::Initialized => "initialized",
+        EscrowStatus::Funded => "funded",
+        EscrowStatus::Settled => "settled",
+        EscrowStatus::Completed => "completed",
+        EscrowStatus::Cancelled => "cancelled",
+        EscrowStatus::Refunded => "refunded",
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use ownmark_sdk::accounts::{AccessMintState, EscrowState, Listing};
+use ownmark_sdk::{PaymentMode, Purchase};
+use crate::client::Client;
+
+pub mod access;
+pub mod escrow;
+pub mod product;
+pub mod sales;
+pub mod split;
+
+/// Payment mode of a listing or escrow mint (`None` = SOL), looking up the mint's token program
+fn payment_mode(client: &Client, mint: Option<Pubkey>) -> Result<PaymentMode> {
+    match mint {
+        None => Ok(PaymentMode::Sol),
+        Some(mint) => Ok(PaymentMode::Spl {
+            mint,
+            token_program: client.owner(&mint)?,
+        }),
+    }
+}
+
+/// Rebuild the purchase behind an escrow from its listing and access mint state
+fn purchase_for_escrow(client: &Client, escrow: &EscrowState) -> Result<Purchase> {
+    let listing: Listing = client.account(&escrow.listing)?;
+    let access_mint_state: AccessMintState = client.account(&listing.access_mint_state)?;
+    let token_program = payment_mode(client, escrow.payment_token_mint)?.token_program();
+    
+    Ok(Purchase::new(
+        escrow.buyer,
+        escrow.seed,
+        escrow.listing,
+        &listing,
+        &access_mint_state,
+        token_program,
+    ))
+}
+
+/// Display name of a payment mint (`None` = SOL)
+fn mint_label(mint: Option<Pubkey>) -> String {
+    mint.map_or_else(|| "SOL".to_string(), |mint| mint.to_string())
+}
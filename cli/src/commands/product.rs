@@ -0,0 +1,175 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use ownmark_sdk::access_mint::state::{AccessConfig, AccessTokenMetadata};
+use ownmark_sdk::accounts::PlatformConfig;
+use ownmark_sdk::distribution::state::CollaboratorShare;
+use ownmark_sdk::payment_escrow::state::ListingConfig;
+use ownmark_sdk::{instructions, pda};
+use solana_sdk::signature::{Keypair, Signer};
+use crate::args::{parse_collaborator, parse_content_id, DustPolicyArg, TransferModeArg};
+use crate::client::Client;
+use super::payment_mode;
+
+#[derive(Subcommand)]
+pub enum ProductCommand {
+    /// Create the access mint, split and listing of a product, with the keypair as creator
+    Create(CreateArgs),
+}
+
+#[derive(Args)]
+pub struct CreateArgs {
+    /// Content identifier as 64 hex characters
+    #[arg(long, value_parser = parse_content_id)]
+    content_id: [u8; 32],
+    
+    /// Price in lamports, or in base units of the payment mint
+    #[arg(long)]
+    price: u64,
+    
+    /// SPL payment mint (SOL when omitted)
+    #[arg(long)]
+    payment_mint: Option<Pubkey>,
+    
+    /// Seed of the access mint, split and listing PDAs
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    
+    /// Access token name
+    #[arg(long)]
+    name: String,
+    
+    /// Access token symbol
+    #[arg(long)]
+    symbol: String,
+    
+    /// URI of the access token's off-chain metadata
+    #[arg(long)]
+    uri: String,
+    
+    /// Collaborator share as PUBKEY:BPS (repeatable)
+    #[arg(long = "collaborator", value_parser = parse_collaborator)]
+    collaborators: Vec<CollaboratorShare>,
+    
+    /// Platform fee in basis points (the platform minimum when omitted)
+    #[arg(long)]
+    platform_fee_bps: Option<u16>,
+    
+    /// Who receives rounding dust
+    #[arg(long, value_enum, default_value_t = DustPolicyArg::Creator)]
+    dust_policy: DustPolicyArg,
+    
+    /// How access tokens behave once minted
+    #[arg(long, value_enum, default_value_t = TransferModeArg::Transferable)]
+    transfer_mode: TransferModeArg,
+    
+    /// Access period per purchase in seconds (0 = permanent)
+    #[arg(long, default_value_t = 0)]
+    access_duration_secs: i64,
+    
+    /// Maximum number of purchases (unlimited when omitted)
+    #[arg(long)]
+    max_supply: Option<u64>,
+    
+    /// Seconds after settlement during which buyers can request a refund
+    #[arg(long, default_value_t = 0)]
+    refund_window_secs: i64,
+    
+    /// Require the creator to settle each funded escrow
+    #[arg(long)]
+    requires_approval: bool,
+    
+    /// Timestamp from which purchases are accepted (0 = immediately)
+    #[arg(long, default_value_t = 0)]
+    sale_start_ts: i64,
+    
+    /// Timestamp after which purchases are rejected (0 = no end)
+    #[arg(long, default_value_t = 0)]
+    sale_end_ts: i64,
+}
+
+impl ProductCommand {
+    pub fn run(self, client: &Client) -> Result<()> {
+        match self {
+            ProductCommand::Create(args) => create(client, args),
+        }
+    }
+}
+
+/// Create all three accounts in one transaction so a product is never left half set up
+fn create(client: &Client, args: CreateArgs) -> Result<()> {
+    let creator = client.payer()?;
+    let payment = payment_mode(client, args.payment_mint)?;
+    let platform_fee_bps = match args.platform_fee_bps {
+        Some(fee) => fee,
+        None => client.account::<PlatformConfig>(&pda::platform_config().0)?.min_platform_fee_bps,
+    };
+    
+    // The escrow program mints access and distributes payments for every sale
+    let escrow_authority = pda::escrow_authority().0;
+    let mint = Keypair::new();
+    let access_mint_state = pda::access_mint_state(&creator.pubkey(), &args.content_id, args.seed).0;
+    let split_state = pda::split(&creator.pubkey(), &args.content_id, args.seed).0;
+    let listing = pda::listing(&creator.pubkey(), &args.content_id, args.seed).0;
+    
+    let mut transaction = vec![
+        instructions::initialize_mint(
+            creator.pubkey(),
+            mint.pubkey(),
+            args.content_id,
+            args.seed,
+            escrow_authority,
+            AccessTokenMetadata {
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+            },
+            AccessConfig {
+                transfer_mode: args.transfer_mode.into(),
+                access_duration_secs: args.access_duration_secs,
+                max_supply: args.max_supply,
+            },
+        ),
+        instructions::initialize_split(
+            creator.pubkey(),
+            args.content_id,
+            platform_fee_bps,
+            args.collaborators,
+            args.seed,
+            escrow_authority,
+            args.dust_policy.into(),
+            payment.mint(),
+        ),
+    ];
+    
+    // SPL splits receive payments in the vault's associated token account
+    transaction.extend(instructions::create_token_account(
+        creator.pubkey(),
+        pda::split_vault(&split_state).0,
+        payment,
+    ));
+    transaction.push(instructions::create_listing(
+        creator.pubkey(),
+        args.content_id,
+        args.price,
+        payment,
+        ListingConfig {
+            requires_approval: args.requires_approval,
+            refund_window_secs: args.refund_window_secs,
+            sale_start_ts: args.sale_start_ts,
+            sale_end_ts: args.sale_end_ts,
+        },
+        args.seed,
+        access_mint_state,
+        split_state,
+    ));
+    
+    let signature = client.send(&transaction, &[&mint])?;
+    
+    println!("Access mint state: {access_mint_state}");
+    println!("Access token mint: {}", mint.pubkey());
+    println!("Split:             {split_state}");
+    println!("Listing:           {listing}");
+    println!("Signature:         {signature}");
+    Ok(())
+}
@@ -0,0 +1,264 @@
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use ownmark_sdk::payment_escrow::events::{EscrowCancelled, EscrowRefunded, EscrowSettled, PaymentReceived};
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiTransactionEncoding,
+};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use crate::client::Client;
+use super::mint_label;
+
+/// Signatures fetched per page (the RPC maximum)
+const PAGE_SIZE: usize = 1000;
+
+#[derive(Subcommand)]
+pub enum SalesCommand {
+    /// Write every payment made against a listing as CSV, oldest first, with the outcome of each sale
+    Export {
+        /// Listing address
+        listing: Pubkey,
+        
+        /// Only look at the most recent transactions touching the listing
+        #[arg(long)]
+        limit: Option<usize>,
+        
+        /// Output file (stdout when omitted)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+impl SalesCommand {
+    pub fn run(self, client: &Client) -> Result<()> {
+        match self {
+            SalesCommand::Export { listing, limit, output } => {
+                let out: Box<dyn Write> = match output {
+                    Some(path) => Box::new(
+                        File::create(&path).with_context(|| format!("failed to create {}", path.display()))?,
+                    ),
+                    None => Box::new(io::stdout().lock()),
+                };
+                export(client, &listing, limit, out)
+            }
+        }
+    }
+}
+
+/// Outcome of a sale, as shown by the events of its escrow
+/// Later outcomes rank higher, so folding with `max` keeps the latest one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum SaleStatus {
+    /// Paid into the escrow, access not granted yet
+    Pending,
+    /// Access granted and the payment sent to the split
+    Completed,
+    /// Refunded to the buyer inside the refund window
+    Refunded,
+    /// Cancelled before settlement and returned to the buyer
+    Cancelled,
+}
+
+impl SaleStatus {
+    fn label(self) -> &'static str {
+        match self {
+            SaleStatus::Pending => "pending",
+            SaleStatus::Completed => "completed",
+            SaleStatus::Refunded => "refunded",
+            SaleStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// A payment found in a transaction that touched the listing
+struct Sale {
+    signature: Signature,
+    slot: u64,
+    payment: PaymentReceived,
+}
+
+/// Rebuild sales from the `PaymentReceived` events of every transaction that touched the listing
+/// Each payment instruction (deposit, buy_and_mint, renew) takes the listing account.
+/// `request_refund` and `cancel_escrow` do not, so the transactions of each paid escrow are read
+/// as well to mark refunded and cancelled sales
+fn export(client: &Client, listing: &Pubkey, limit: Option<usize>, mut out: impl Write) -> Result<()> {
+    let mut seen = HashSet::new();
+    let mut sales = Vec::new();
+    let mut statuses = HashMap::new();
+    
+    for signature in signatures(client, listing, limit)?.into_iter().rev() {
+        let transaction = fetch_transaction(client, &signature)?;
+        seen.insert(signature);
+        
+        for event in escrow_events(&transaction) {
+            if let Some(payment) = decode::<PaymentReceived>(&event) {
+                statuses.entry(payment.escrow).or_insert(SaleStatus::Pending);
+                sales.push(Sale { signature, slot: transaction.slot, payment });
+            } else if let Some((escrow, status)) = outcome(&event) {
+                record(&mut statuses, escrow, status);
+            }
+        }
+    }
+    
+    let escrows: Vec<Pubkey> = statuses.keys().copied().collect();
+    for escrow in escrows {
+        for signature in signatures(client, &escrow, None)? {
+            if !seen.insert(signature) {
+                continue;
+            }
+            for event in escrow_events(&fetch_transaction(client, &signature)?) {
+                if let Some((escrow, status)) = outcome(&event) {
+                    record(&mut statuses, escrow, status);
+                }
+            }
+        }
+    }
+    
+    writeln!(out, "signature,slot,timestamp,escrow,buyer,currency,amount,renewal,status")?;
+    for Sale { signature, slot, payment } in sales {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            signature,
+            slot,
+            payment.timestamp,
+            payment.escrow,
+            payment.buyer,
+            mint_label(payment.payment_token_mint),
+            payment.amount,
+            payment.renewal,
+            statuses[&payment.escrow].label()
+        )?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Keep the latest outcome seen for `escrow`
+fn record(statuses: &mut HashMap<Pubkey, SaleStatus>, escrow: Pubkey, status: SaleStatus) {
+    let current = statuses.entry(escrow).or_insert(status);
+    *current = (*current).max(status);
+}
+
+/// Escrow and outcome of a settlement, refund or cancellation event
+fn outcome(event: &[u8]) -> Option<(Pubkey, SaleStatus)> {
+    if let Some(settled) = decode::<EscrowSettled>(event) {
+        Some((settled.escrow, SaleStatus::Completed))
+    } else if let Some(refunded) = decode::<EscrowRefunded>(event) {
+        Some((refunded.escrow, SaleStatus::Refunded))
+    } else {
+        decode::<EscrowCancelled>(event).map(|cancelled| (cancelled.escrow, SaleStatus::Cancelled))
+    }
+}
+
+/// Decode an event of type `E`, skipping its discriminator
+fn decode<E: AnchorDeserialize + Discriminator>(event: &[u8]) -> Option<E> {
+    E::try_from_slice(event.strip_prefix(E::DISCRIMINATOR)?).ok()
+}
+
+fn fetch_transaction(client: &Client, signature: &Signature) -> Result<EncodedConfirmedTransactionWithStatusMeta> {
+    client
+        .rpc
+        .get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(client.rpc.commitment()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .with_context(|| format!("failed to fetch transaction {signature}"))
+}
+
+/// Successful transactions that touched `address`, newest first
+fn signatures(client: &Client, address: &Pubkey, limit: Option<usize>) -> Result<Vec<Signature>> {
+    let mut signatures = Vec::new();
+    let mut before = None;
+    
+    loop {
+        let remaining = limit.map_or(PAGE_SIZE, |limit| limit.saturating_sub(signatures.len()));
+        let page = client
+            .rpc
+            .get_signatures_for_address_with_config(
+                address,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(remaining.min(PAGE_SIZE)),
+                    commitment: Some(client.rpc.commitment()),
+                },
+            )
+            .with_context(|| format!("failed to fetch signatures of {address}"))?;
+        let page_len = page.len();
+        
+        for status in page {
+            let signature = Signature::from_str(&status.signature)?;
+            before = Some(signature);
+            if status.err.is_none() {
+                signatures.push(signature);
+            }
+        }
+        
+        let done = limit.is_some_and(|limit| signatures.len() >= limit);
+        if page_len < PAGE_SIZE || done {
+            return Ok(signatures);
+        }
+    }
+}
+
+/// Events emitted by the escrow program in a transaction, each starting with its discriminator
+/// Events are self-invoked inner instructions: the event tag, the event discriminator, then the event
+fn escrow_events(transaction: &EncodedConfirmedTransactionWithStatusMeta) -> Vec<Vec<u8>> {
+    let Some(meta) = &transaction.transaction.meta else {
+        return Vec::new();
+    };
+    let Some(decoded) = transaction.transaction.transaction.decode() else {
+        return Vec::new();
+    };
+    let account_keys = decoded.message.static_account_keys();
+    let inner_instructions: Option<Vec<_>> = meta.inner_instructions.clone().into();
+    
+    inner_instructions
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|inner| &inner.instructions)
+        .filter_map(|instruction| match instruction {
+            UiInstruction::Compiled(compiled) => Some(compiled),
+            UiInstruction::Parsed(_) => None,
+        })
+        .filter(|compiled| {
+            account_keys.get(compiled.program_id_index as usize) == Some(&ownmark_sdk::payment_escrow::ID)
+        })
+        .filter_map(|compiled| bs58::decode(&compiled.data).into_vec().ok())
+        .filter_map(|data| data.strip_prefix(EVENT_IX_TAG_LE).map(<[u8]>::to_vec))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn latest_outcome_wins() {
+        let escrow = Pubkey::new_unique();
+        let mut statuses = HashMap::from([(escrow, SaleStatus::Pending)]);
+        
+        record(&mut statuses, escrow, SaleStatus::Refunded);
+        // The settlement is read after the refund when it came from an earlier listing transaction
+        record(&mut statuses, escrow, SaleStatus::Completed);
+        assert_eq!(statuses[&escrow], SaleStatus::Refunded);
+        
+        let cancelled = Pubkey::new_unique();
+        record(&mut statuses, cancelled, SaleStatus::Cancelled);
+        assert_eq!(statuses[&cancelled].label(), "cancelled");
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::{Pubkey, System};
+use anchor_lang::Id;
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use ownmark_sdk::accounts::SplitState;
+use ownmark_sdk::distribution::state::CollaboratorShare;
+use ownmark_sdk::instructions::{self, required_co_signers};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use crate::args::{parse_collaborator, DustPolicyArg};
+use crate::client::Client;
+
+#[derive(Subcommand)]
+pub enum SplitCommand {
+    /// Show a split's terms and unclaimed balances
+    Show {
+        /// Split state address
+        split: Pubkey,
+    },
+    
+    /// Change an unlocked split, with the keypair as creator
+    Update(UpdateArgs),
+}
+
+#[derive(Args)]
+pub struct UpdateArgs {
+    /// Split state address
+    split: Pubkey,
+    
+    /// New collaborator share as PUBKEY:BPS (repeatable; omitted collaborators are removed)
+    #[arg(long = "collaborator", value_parser = parse_collaborator)]
+    collaborators: Vec<CollaboratorShare>,
+    
+    /// New platform fee in basis points (unchanged when omitted)
+    #[arg(long)]
+    platform_fee_bps: Option<u16>,
+    
+    /// New dust policy (unchanged when omitted)
+    #[arg(long, value_enum)]
+    dust_policy: Option<DustPolicyArg>,
+    
+    /// Lock the split permanently
+    #[arg(long)]
+    lock: bool,
+    
+    /// Keypair file of a collaborator whose share is reduced or removed (repeatable)
+    #[arg(long = "co-signer")]
+    co_signers: Vec<String>,
+}
+
+impl SplitCommand {
+    pub fn run(self, client: &Client) -> Result<()> {
+        match self {
+            SplitCommand::Show { split } => show(client, &split),
+            SplitCommand::Update(args) => update(client, args),
+        }
+    }
+}
+
+fn show(client: &Client, address: &Pubkey) -> Result<()> {
+    let split: SplitState = client.account(address)?;
+    let currency = if split.payment_token_mint == System::id() {
+        "SOL".to_string()
+    } else {
+        split.payment_token_mint.to_string()
+    };
+    let creator_share_bps = 10_000
+        - split.platform_fee_bps
+        - split.collaborators.iter().map(|c| c.share_bps).sum::<u16>();
+    
+    println!("Split:          {address}");
+    println!("Creator:        {}", split.creator);
+    println!("Content id:     {}", hex::encode(split.content_id));
+    println!("Currency:       {currency}");
+    println!("Distributor:    {}", split.distributor);
+    println!("Dust policy:    {:?}", split.dust_policy);
+    println!("Carried dust:   {}", split.carried_dust);
+    println!("Held payments:  {}", split.held_amount);
+    println!("Locked:         {}", split.locked);
+    println!();
+    println!("{:<44}  {:>6}  {:>20}", "RECIPIENT", "BPS", "UNCLAIMED");
+    println!(
+        "{:<44}  {:>6}  {:>20}",
+        split.platform_treasury, split.platform_fee_bps, split.platform_unclaimed
+    );
+    println!("{:<44}  {:>6}  {:>20}", split.creator, creator_share_bps, split.creator_unclaimed);
+    for collaborator in &split.collaborators {
+        println!(
+            "{:<44}  {:>6}  {:>20}",
+            collaborator.pubkey, collaborator.share_bps, collaborator.unclaimed
+        );
+    }
+    Ok(())
+}
+
+fn update(client: &Client, args: UpdateArgs) -> Result<()> {
+    let split: SplitState = client.account(&args.split)?;
+    
+    // Every collaborator losing share must sign, so check the keypairs before sending
+    let co_signers = args
+        .co_signers
+        .iter()
+        .map(|path| read_keypair_file(path).map_err(|err| anyhow::anyhow!("failed to read keypair {path}: {err}")))
+        .collect::<Result<Vec<Keypair>>>()?;
+    let missing: Vec<String> = required_co_signers(&split, &args.collaborators)
+        .into_iter()
+        .filter(|required| !co_signers.iter().any(|signer| signer.pubkey() == *required))
+        .map(|required| required.to_string())
+        .collect();
+    if !missing.is_empty() {
+        bail!("missing --co-signer for: {}", missing.join(", "));
+    }
+    
+    let instruction = instructions::update_split(
+        &split,
+        args.collaborators,
+        args.platform_fee_bps.unwrap_or(split.platform_fee_bps),
+        args.dust_policy.map_or(split.dust_policy, Into::into),
+        args.lock,
+    );
+    let signers: Vec<&Keypair> = co_signers.iter().collect();
+    let signature = client.send(&[instruction], &signers)?;
+    
+    println!("Updated split {}", args.split);
+    println!("Signature: {signature}");
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! `ownmark` command-line tool for creators and operators
+//!
+//! Works against any cluster: `--url` defaults to a local test validator and `--keypair`
+//! to the Solana CLI's default keypair. Read-only commands never load the keypair.
+
+mod args;
+mod client;
+mod commands;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use client::Client;
+use commands::access::AccessCommand;
+use commands::escrow::EscrowCommand;
+use commands::product::ProductCommand;
+use commands::sales::SalesCommand;
+use commands::split::SplitCommand;
+
+#[derive(Parser)]
+#[command(name = "ownmark", version, about = "Manage products, splits, escrows and sales")]
+struct Cli {
+    /// RPC URL of the cluster
+    #[arg(short, long, global = true, env = "OWNMARK_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+    
+    /// Keypair file that signs and pays for transactions
+    #[arg(short, long, global = true, env = "OWNMARK_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create products for sale
+    #[command(subcommand)]
+    Product(ProductCommand),
+    
+    /// Inspect and update revenue splits
+    #[command(subcommand)]
+    Split(SplitCommand),
+    
+    /// List and cancel escrows
+    #[command(subcommand)]
+    Escrow(EscrowCommand),
+    
+    /// Check a wallet's access to a product
+    #[command(subcommand)]
+    Access(AccessCommand),
+    
+    /// Export the sales of a listing
+    #[command(subcommand)]
+    Sales(SalesCommand),
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let client = Client::new(&cli.url, &cli.keypair);
+    
+    match cli.command {
+        Command::Product(command) => command.run(&client),
+        Command::Split(command) => command.run(&client),
+        Command::Escrow(command) => command.run(&client),
+        Command::Access(command) => command.run(&client),
+        Command::Sales(command) => command.run(&client),
+    }
+}
@@ -35,6 +35,33 @@ pub enum DistributionError {
     #[msg("Invalid recipient")]
     InvalidRecipient,
     
-    #[msg("Distribution already completed")]
-    AlreadyDistributed,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    
+    #[msg("Split is locked")]
+    SplitLocked,
+    
+    #[msg("Collaborator must sign to reduce their share")]
+    MissingCollaboratorSignature,
+    
+    #[msg("Collaborator still has an unclaimed balance")]
+    UnclaimedBalance,
+    
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    
+    #[msg("Payment token mint does not match split")]
+    InvalidPaymentMint,
+    
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+    
+    #[msg("Payment mint uses an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+    
+    #[msg("Vault still holds unclaimed or undistributed funds")]
+    VaultNotEmpty,
+    
+    #[msg("Platform is paused")]
+    PlatformPaused,
 }
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollaboratorShare, DustPolicy, PlatformSettings};
+
+/// Emitted when a creator sets up a new split
+#[event]
+pub struct SplitInitialized {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Creator who owns the split
+    pub creator: Pubkey,
+    
+    /// Content the split belongs to
+    pub content_id: [u8; 32],
+    
+    /// Seed used for PDA derivation
+    pub seed: u64,
+    
+    /// Currency the split accepts (System::id() for SOL)
+    pub payment_token_mint: Pubkey,
+    
+    /// Platform fee in basis points
+    pub platform_fee_bps: u16,
+    
+    /// Treasury receiving the platform fee
+    pub platform_treasury: Pubkey,
+    
+    /// Authority allowed to distribute and refund
+    pub distributor: Pubkey,
+    
+    /// How rounding remainders are assigned
+    pub dust_policy: DustPolicy,
+    
+    /// Collaborators and their shares
+    pub collaborators: Vec<CollaboratorShare>,
+}
+
+/// Emitted when a payment is credited to the split's recipients
+/// Line items plus `carried_dust` always add up to `amount` plus the dust carried in
+#[event]
+pub struct Distributed {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Amount distributed by this call
+    pub amount: u64,
+    
+    /// Platform treasury and the fee credited to it
+    pub platform: RecipientAmount,
+    
+    /// Creator and the share credited to them
+    pub creator: RecipientAmount,
+    
+    /// Collaborators and their credited shares, in split order
+    pub collaborators: Vec<RecipientAmount>,
+    
+    /// Remainder held back for the next distribution
+    pub carried_dust: u64,
+    
+    /// Time of the distribution
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment enters the vault held for a refund window
+#[event]
+pub struct PaymentHeld {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Amount held by this call
+    pub amount: u64,
+    
+    /// Total held in the vault after this call
+    pub held_amount: u64,
+    
+    /// Time the payment was held
+    pub timestamp: i64,
+}
+
+/// Emitted when a held payment is credited to the split's recipients
+/// The matching `Distributed` event follows in the same instruction
+#[event]
+pub struct PaymentReleased {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Amount released by this call
+    pub amount: u64,
+    
+    /// Total still held in the vault after this call
+    pub held_amount: u64,
+    
+    /// Time of the release
+    pub timestamp: i64,
+}
+
+/// Emitted when a held payment is returned from the vault to a buyer
+#[event]
+pub struct PaymentRefunded {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Buyer receiving the refund
+    pub recipient: Pubkey,
+    
+    /// Amount refunded (before any transfer fee)
+    pub amount: u64,
+    
+    /// Total still held in the vault after this call
+    pub held_amount: u64,
+    
+    /// Time of the refund
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient withdraws their credited balance
+#[event]
+pub struct Claimed {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Creator, platform treasury or collaborator who claimed
+    pub recipient: Pubkey,
+    
+    /// Amount withdrawn (before any transfer fee)
+    pub amount: u64,
+    
+    /// Time of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator changes a split's terms
+#[event]
+pub struct SplitUpdated {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Platform fee in basis points
+    pub platform_fee_bps: u16,
+    
+    /// Treasury receiving the platform fee
+    pub platform_treasury: Pubkey,
+    
+    /// How rounding remainders are assigned
+    pub dust_policy: DustPolicy,
+    
+    /// Collaborators and their shares
+    pub collaborators: Vec<CollaboratorShare>,
+    
+    /// Whether the split is now permanently frozen
+    pub locked: bool,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator closes a split and its vault
+#[event]
+pub struct SplitClosed {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Creator receiving the swept balance and rent
+    pub creator: Pubkey,
+    
+    /// Balance swept to the creator (carried dust plus stray deposits), excluding rent
+    pub swept_amount: u64,
+    
+    /// Time of the close
+    pub timestamp: i64,
+}
+
+/// Emitted once when the program's upgrade authority creates the platform config
+#[event]
+pub struct PlatformConfigInitialized {
+    /// Platform config PDA
+    pub platform_config: Pubkey,
+    
+    /// First platform admin
+    pub admin: Pubkey,
+    
+    /// Initial settings
+    pub settings: PlatformSettings,
+    
+    /// Time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin changes the platform treasury, fee bounds or collaborator limit
+#[event]
+pub struct PlatformConfigUpdated {
+    /// Platform config PDA
+    pub platform_config: Pubkey,
+    
+    /// New settings
+    pub settings: PlatformSettings,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin sets or clears the platform-wide pause flag
+#[event]
+pub struct PlatformPauseChanged {
+    /// Platform config PDA
+    pub platform_config: Pubkey,
+    
+    /// Whether the platform is now paused
+    pub paused: bool,
+    
+    /// Time of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin nominates a new admin
+#[event]
+pub struct AdminTransferProposed {
+    /// Platform config PDA
+    pub platform_config: Pubkey,
+    
+    /// Current admin
+    pub admin: Pubkey,
+    
+    /// Nominee who must call `accept_admin`
+    pub pending_admin: Pubkey,
+    
+    /// Time of the nomination
+    pub timestamp: i64,
+}
+
+/// Emitted when the nominee accepts the admin role
+#[event]
+pub struct AdminTransferred {
+    /// Platform config PDA
+    pub platform_config: Pubkey,
+    
+    /// Admin before the transfer
+    pub old_admin: Pubkey,
+    
+    /// Admin after the transfer
+    pub new_admin: Pubkey,
+    
+    /// Time of the transfer
+    pub timestamp: i64,
+}
+
+/// Amount credited to one recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RecipientAmount {
+    /// Recipient wallet
+    pub recipient: Pubkey,
+    
+    /// Amount credited
+    pub amount: u64,
+}
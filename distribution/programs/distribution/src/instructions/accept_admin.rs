@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Complete an admin transfer started by `transfer_admin` (nominee only)
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let old_admin = platform_config.admin;
+    
+    platform_config.admin = ctx.accounts.new_admin.key();
+    platform_config.pending_admin = None;
+    
+    msg!("Platform admin transferred to: {}", platform_config.admin);
+    
+    emit_cpi!(AdminTransferred {
+        platform_config: platform_config.key(),
+        old_admin,
+        new_admin: platform_config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Nominated admin
+    pub new_admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.pending_admin == Some(new_admin.key()) @ DistributionError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use crate::utils::VaultTransfer;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Withdraw everything credited to the signer (creator, platform treasury or collaborator)
+pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    let recipient = ctx.accounts.recipient.key();
+    
+    let amount = split_state.take_unclaimed(&recipient)?;
+    require!(amount > 0, DistributionError::NothingToClaim);
+    
+    let split_state_key = split_state.key();
+    let vault_bump = ctx.bumps.vault;
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        split_state_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    if split_state.payment_token_mint == System::id() {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        msg!("Claimed {} lamports by {}", amount, recipient);
+    } else {
+        VaultTransfer {
+            split_state,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+            recipient,
+            recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        }
+        .execute(amount, signer_seeds)?;
+        msg!("Claimed {} tokens by {}", amount, recipient);
+    }
+    
+    emit_cpi!(Claimed {
+        split_state: split_state_key,
+        recipient,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    /// Creator, platform treasury or collaborator withdrawing their balance
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Vault holding the funds (SOL or SPL)
+    /// CHECK: Vault PDA validated by seeds
+    #[account(
+        mut,
+        seeds = [b"vault", split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL splits)
+    /// CHECK: Validated against the split when the split uses an SPL mint
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Vault token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Recipient token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    #[account(mut)]
+    pub recipient_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for SPL splits (SPL Token or Token-2022)
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
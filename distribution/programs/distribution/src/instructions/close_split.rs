@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token_interface::{self, CloseAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+
+/// Retire a split: sweep the vault to the creator, close it and return all rent
+/// Every credited balance must have been claimed and no payment may still be held for a refund window;
+/// whatever is left (carried dust plus anything sent to the vault directly) goes to the creator
+pub fn close_split(ctx: Context<CloseSplit>) -> Result<()> {
+    let split_state = &ctx.accounts.split_state;
+    let creator = ctx.accounts.creator.key();
+    
+    // Recipients must claim their balances before the split disappears
+    require!(
+        split_state.total_owed()? == split_state.carried_dust,
+        DistributionError::VaultNotEmpty
+    );
+    
+    // Held payments may still be refunded to their buyers or released to recipients
+    require!(
+        split_state.held_amount == 0,
+        DistributionError::VaultNotEmpty
+    );
+    
+    // Funds sent to the vault outside a purchase must not block the close; they are swept with the dust
+    let balance = vault_balance(
+        split_state,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+    )?;
+    require!(
+        balance >= split_state.carried_dust,
+        DistributionError::InsufficientFunds
+    );
+    
+    let split_state_key = split_state.key();
+    let vault_bump = ctx.bumps.vault;
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        split_state_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    if split_state.payment_token_mint == System::id() {
+        // Sweep the balance together with the rent-exempt reserve funded at `initialize_split`
+        // Draining every lamport also closes the vault account
+        let vault_lamports = ctx.accounts.vault.lamports();
+        if vault_lamports > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: ctx.accounts.creator.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                vault_lamports,
+            )?;
+        }
+        msg!("Swept {} lamports of balance and {} of vault rent to creator", balance, vault_lamports - balance);
+    } else {
+        if balance > 0 {
+            VaultTransfer {
+                split_state,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                recipient: creator,
+                recipient_token_account: ctx.accounts.creator_token_account.to_account_info(),
+            }
+            .execute(balance, signer_seeds)?;
+        }
+        
+        // `vault_balance` validated the vault token account; the token program must own it
+        require!(
+            *ctx.accounts.vault_token_account.owner == ctx.accounts.token_program.key(),
+            DistributionError::InvalidTokenProgram
+        );
+        require!(
+            ctx.accounts.payment_token_mint.key() == split_state.payment_token_mint,
+            DistributionError::InvalidPaymentMint
+        );
+        
+        // Token-2022 accounts holding withheld transfer fees cannot be closed
+        harvest_withheld_fees(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_token_mint.to_account_info(),
+            &ctx.accounts.vault_token_account.to_account_info(),
+        )?;
+        
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_token_account.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        msg!("Swept {} tokens of balance to creator and closed the vault token account", balance);
+    }
+    
+    msg!("Split closed by creator: {}", creator);
+    
+    emit_cpi!(SplitClosed {
+        split_state: split_state_key,
+        creator,
+        swept_amount: balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSplit<'info> {
+    /// Creator who owns the split, receiving the remaining balance and all rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::InvalidCreator,
+        close = creator,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Vault holding the funds (SOL or SPL)
+    /// CHECK: Vault PDA validated by seeds
+    #[account(
+        mut,
+        seeds = [b"vault", split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL splits)
+    /// CHECK: Validated against the split when the split uses an SPL mint
+    #[account(mut)]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Vault token account for SPL splits (closed to the creator)
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Creator token account receiving the remaining balance for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint and a balance remains
+    #[account(mut)]
+    pub creator_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for SPL splits (SPL Token or Token-2022)
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
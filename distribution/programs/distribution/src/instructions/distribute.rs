@@ -1,176 +1,90 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer, System};
-use anchor_spl::token::{self, Transfer as SplTransfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
 
-/// Distribute funds from vault to all recipients
+/// Credit funds already in the vault to the creator, platform and collaborators
 /// Called via CPI from payment escrow program
-pub fn distribute<'info>(
-    ctx: Context<'_, '_, '_, 'info, Distribute<'info>>,
-    amount: u64,
-) -> Result<()> {
+/// No transfers happen here - each recipient withdraws their balance with `claim`
+pub fn distribute(ctx: Context<Distribute>, amount: u64) -> Result<()> {
+    // Emergency stop: credited funds stay claimable, but nothing new is credited while paused
+    require!(
+        !ctx.accounts.platform_config.paused,
+        DistributionError::PlatformPaused
+    );
+    
     let split_state = &mut ctx.accounts.split_state;
     let clock = Clock::get()?;
     
     // Validate amounts
     require!(amount > 0, DistributionError::InsufficientFunds);
     
-    // Calculate distribution amounts
-    let platform_amount = split_state.calculate_platform_fee(amount)?;
-    let creator_amount = split_state.calculate_creator_share(amount)?;
+    // Follow treasury changes made by `update_platform_config`; the new treasury
+    // takes over whatever platform fee is still unclaimed
+    let treasury = ctx.accounts.platform_config.treasury;
+    if split_state.platform_treasury != treasury {
+        msg!("Platform treasury moved: {} -> {}", split_state.platform_treasury, treasury);
+        split_state.platform_treasury = treasury;
+    }
     
-    // Get vault bump for signing
-    let split_state_key = split_state.key();
-    let vault_bump = ctx.bumps.vault;
-    let vault_seeds = &[
-        b"vault".as_ref(),
-        split_state_key.as_ref(),
-        &[vault_bump],
-    ];
-    let signer_seeds = &[&vault_seeds[..]];
+    let allocation = split_state.credit(amount)?;
     
-    // Determine if SOL or SPL payment
-    let is_sol_payment = ctx.accounts.payment_token_mint.key() == System::id();
-    
-    if is_sol_payment {
-        // Distribute SOL using system program transfers signed by vault PDA
-        
-        // Transfer to platform treasury
-        if platform_amount > 0 {
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.vault.to_account_info(),
-                        to: ctx.accounts.platform_treasury.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                platform_amount,
-            )?;
-            msg!("Distributed {} lamports to platform", platform_amount);
-        }
-        
-        // Transfer to collaborators
-        for (i, collaborator) in split_state.collaborators.iter().enumerate() {
-            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.share_bps)?;
-            
-            if collab_amount > 0 {
-                // Get collaborator account from remaining accounts
-                let collab_account = &ctx.remaining_accounts[i];
-                require!(
-                    collab_account.key() == collaborator.pubkey,
-                    DistributionError::InvalidCollaborator
-                );
-                
-                transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.system_program.to_account_info(),
-                        Transfer {
-                            from: ctx.accounts.vault.to_account_info(),
-                            to: collab_account.to_account_info(),
-                        },
-                        signer_seeds,
-                    ),
-                    collab_amount,
-                )?;
-                msg!("Distributed {} lamports to collaborator {}", collab_amount, collaborator.pubkey);
-            }
-        }
-        
-        // Transfer remaining to creator
-        if creator_amount > 0 {
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.vault.to_account_info(),
-                        to: ctx.accounts.creator.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                creator_amount,
-            )?;
-            msg!("Distributed {} lamports to creator", creator_amount);
-        }
-    } else {
-        // Distribute SPL tokens
-        require!(
-            ctx.accounts.vault_token_account.key() != System::id(),
-            DistributionError::InvalidVault
-        );
-        
-        // Transfer to platform treasury
-        if platform_amount > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
-                        from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: ctx.accounts.platform_treasury_token_account.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                platform_amount,
-            )?;
-            msg!("Distributed {} tokens to platform", platform_amount);
-        }
-        
-        // Transfer to collaborators
-        for (i, collaborator) in split_state.collaborators.iter().enumerate() {
-            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.share_bps)?;
-            
-            if collab_amount > 0 {
-                // Get collaborator token account from remaining accounts
-                let collab_token_account = &ctx.remaining_accounts[i];
-                
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        SplTransfer {
-                            from: ctx.accounts.vault_token_account.to_account_info(),
-                            to: collab_token_account.to_account_info(),
-                            authority: ctx.accounts.vault.to_account_info(),
-                        },
-                        signer_seeds,
-                    ),
-                    collab_amount,
-                )?;
-                msg!("Distributed {} tokens to collaborator", collab_amount);
-            }
-        }
-        
-        // Transfer remaining to creator
-        if creator_amount > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
-                        from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: ctx.accounts.creator_token_account.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                creator_amount,
-            )?;
-            msg!("Distributed {} tokens to creator", creator_amount);
-        }
-    }
+    // Everything owed to recipients, plus payments still held, must always be backed by the vault
+    let balance = vault_balance(
+        split_state,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+    )?;
+    require!(
+        split_state.total_committed()? <= balance,
+        DistributionError::InsufficientFunds
+    );
     
     // Update last distributed timestamp
     split_state.last_distributed_ts = clock.unix_timestamp;
     
-    msg!("Distribution completed: platform={}, creator={}, collaborators={}", 
-        platform_amount, creator_amount, split_state.collaborators.len());
+    msg!("Distribution credited: amount={}, collaborators={}", 
+        amount, split_state.collaborators.len());
+    
+    let event = Distributed {
+        split_state: split_state.key(),
+        amount,
+        platform: RecipientAmount {
+            recipient: split_state.platform_treasury,
+            amount: allocation.platform,
+        },
+        creator: RecipientAmount {
+            recipient: split_state.creator,
+            amount: allocation.creator,
+        },
+        collaborators: split_state.collaborators
+            .iter()
+            .zip(allocation.collaborators.iter())
+            .map(|(collaborator, collab_amount)| RecipientAmount {
+                recipient: collaborator.pubkey,
+                amount: *collab_amount,
+            })
+            .collect(),
+        carried_dust: allocation.carried_dust,
+        timestamp: clock.unix_timestamp,
+    };
+    emit_cpi!(event);
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Distribute<'info> {
+    /// Authorized distributor
+    /// Only the distributor may credit vault funds, since part of the vault
+    /// can be held back for purchases still inside their refund window
+    #[account(
+        constraint = authority.key() == split_state.distributor @ DistributionError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+    
     /// Split state PDA
     #[account(
         mut,
@@ -184,50 +98,22 @@ pub struct Distribute<'info> {
     )]
     pub split_state: Account<'info, SplitState>,
     
+    /// Platform config holding the pause flag and the current treasury
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     /// Vault holding the funds (SOL or SPL)
     /// CHECK: Vault PDA validated by seeds
     #[account(
-        mut,
         seeds = [b"vault", split_state.key().as_ref()],
         bump,
     )]
     pub vault: UncheckedAccount<'info>,
     
-    /// Creator receiving their share
-    /// CHECK: Creator validated from split_state
-    #[account(mut)]
-    pub creator: UncheckedAccount<'info>,
-    
-    /// Platform treasury receiving platform fees
-    /// CHECK: Platform treasury validated from split_state
-    #[account(mut)]
-    pub platform_treasury: UncheckedAccount<'info>,
-    
-    /// Payment token mint (System::id() for SOL)
-    /// CHECK: Used to determine payment type
-    pub payment_token_mint: UncheckedAccount<'info>,
-    
-    /// Vault token account for SPL payments
-    /// CHECK: Optional, validated when SPL payment is used
-    #[account(mut)]
+    /// Vault token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
     pub vault_token_account: UncheckedAccount<'info>,
-    
-    /// Creator token account for SPL payments
-    /// CHECK: Optional, validated when SPL payment is used
-    #[account(mut)]
-    pub creator_token_account: UncheckedAccount<'info>,
-    
-    /// Platform treasury token account for SPL payments
-    /// CHECK: Optional, validated when SPL payment is used
-    #[account(mut)]
-    pub platform_treasury_token_account: UncheckedAccount<'info>,
-    
-    /// Token program for SPL payments
-    /// CHECK: Optional, validated when SPL payment is used
-    pub token_program: UncheckedAccount<'info>,
-    
-    /// System program
-    pub system_program: Program<'info, System>,
-    
-    // Remaining accounts: collaborator accounts (SOL) or token accounts (SPL)
 }
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::utils::vault_balance;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Record a payment moved into the vault that must wait out a refund window
+/// Called via CPI from payment escrow program; the payment is later credited with `release`
+/// or returned to the buyer with `refund`
+pub fn hold(ctx: Context<Hold>, amount: u64) -> Result<()> {
+    require!(amount > 0, DistributionError::InsufficientFunds);
+    
+    let split_state = &mut ctx.accounts.split_state;
+    split_state.held_amount = split_state.held_amount
+        .checked_add(amount)
+        .ok_or(DistributionError::NumericalOverflow)?;
+    
+    // The held payment must already be in the vault, on top of everything owed
+    let balance = vault_balance(
+        split_state,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+    )?;
+    require!(
+        split_state.total_committed()? <= balance,
+        DistributionError::InsufficientFunds
+    );
+    
+    msg!("Payment held: amount={}, total held={}", amount, split_state.held_amount);
+    
+    emit_cpi!(PaymentHeld {
+        split_state: split_state.key(),
+        amount,
+        held_amount: split_state.held_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Hold<'info> {
+    /// Authorized distributor
+    #[account(
+        constraint = authority.key() == split_state.distributor @ DistributionError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Vault holding the funds (SOL or SPL)
+    /// CHECK: Vault PDA validated by seeds
+    #[account(
+        seeds = [b"vault", split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Vault token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    pub vault_token_account: UncheckedAccount<'info>,
+}
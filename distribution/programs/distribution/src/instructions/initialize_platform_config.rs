@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::program::Distribution;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Create the platform config singleton
+/// Only the program's upgrade authority can create it; it becomes the first admin
+pub fn initialize_platform_config(
+    ctx: Context<InitializePlatformConfig>,
+    settings: PlatformSettings,
+) -> Result<()> {
+    settings.validate()?;
+    
+    let platform_config = &mut ctx.accounts.platform_config;
+    
+    platform_config.admin = ctx.accounts.admin.key();
+    platform_config.pending_admin = None;
+    platform_config.apply_settings(&settings);
+    platform_config.paused = false;
+    platform_config.bump = ctx.bumps.platform_config;
+    
+    msg!("Platform config initialized by admin: {}, settings: {:?}", 
+        platform_config.admin, settings);
+    
+    emit_cpi!(PlatformConfigInitialized {
+        platform_config: platform_config.key(),
+        admin: platform_config.admin,
+        settings,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    /// Upgrade authority of the distribution program, becoming the platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        init,
+        payer = admin,
+        space = PlatformConfig::LEN,
+        seeds = [PlatformConfig::SEED],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// This program, used to locate its program data account
+    #[account(
+        constraint = distribution_program.programdata_address()? == Some(program_data.key()) @ DistributionError::Unauthorized,
+    )]
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Program data account holding the upgrade authority
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ DistributionError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
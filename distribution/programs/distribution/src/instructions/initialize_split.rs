@@ -1,26 +1,42 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::utils::{sol_vault_reserve, validate_payment_mint};
 use crate::state::*;
-use crate::errors::*;
+use crate::events::*;
 
 /// Initialize a new split configuration for content
+/// The platform fee must fall within the platform config bounds; fees go to the platform treasury
 pub fn initialize_split(
     ctx: Context<InitializeSplit>,
     content_id: [u8; 32],
     platform_fee_bps: u16,
-    collaborators: Vec<Collaborator>,
+    collaborators: Vec<CollaboratorShare>,
     seed: u64,
+    distributor: Pubkey,
+    dust_policy: DustPolicy,
 ) -> Result<()> {
-    // Validate platform fee (max 10%)
-    require!(
-        platform_fee_bps <= 1000,
-        DistributionError::InvalidPlatformFee
-    );
+    // Validate platform fee and collaborator count against the platform policy
+    let platform_config = &ctx.accounts.platform_config;
+    platform_config.validate_split_terms(platform_fee_bps, collaborators.len())?;
+    SplitState::validate_collaborators(&collaborators)?;
     
-    // Validate collaborators count (max 10)
-    require!(
-        collaborators.len() <= 10,
-        DistributionError::TooManyCollaborators
-    );
+    // SPL splits must use a mint owned by a supported token program
+    if ctx.accounts.payment_token_mint.key() != System::id() {
+        validate_payment_mint(&ctx.accounts.payment_token_mint)?;
+    } else {
+        // Fund the SOL vault with its rent-exempt reserve so claims and refunds of any size
+        // can never leave it below the rent-exempt minimum; `close_split` returns the reserve
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            sol_vault_reserve()?,
+        )?;
+    }
     
     let split_state = &mut ctx.accounts.split_state;
     let clock = Clock::get()?;
@@ -29,8 +45,23 @@ pub fn initialize_split(
     split_state.content_id = content_id;
     split_state.creator = ctx.accounts.creator.key();
     split_state.platform_fee_bps = platform_fee_bps;
-    split_state.platform_treasury = ctx.accounts.platform_treasury.key();
-    split_state.collaborators = collaborators;
+    split_state.platform_treasury = platform_config.treasury;
+    split_state.distributor = distributor;
+    split_state.locked = false;
+    split_state.payment_token_mint = ctx.accounts.payment_token_mint.key();
+    split_state.creator_unclaimed = 0;
+    split_state.platform_unclaimed = 0;
+    split_state.dust_policy = dust_policy;
+    split_state.carried_dust = 0;
+    split_state.held_amount = 0;
+    split_state.collaborators = collaborators
+        .iter()
+        .map(|c| Collaborator {
+            pubkey: c.pubkey,
+            share_bps: c.share_bps,
+            unclaimed: 0,
+        })
+        .collect();
     split_state.last_distributed_ts = clock.unix_timestamp;
     split_state.seed = seed;
     split_state.bump = ctx.bumps.split_state;
@@ -43,19 +74,40 @@ pub fn initialize_split(
     msg!("Platform fee: {}bps, Collaborators: {}", 
         platform_fee_bps, split_state.collaborators.len());
     
+    emit_cpi!(SplitInitialized {
+        split_state: split_state.key(),
+        creator: split_state.creator,
+        content_id,
+        seed,
+        payment_token_mint: split_state.payment_token_mint,
+        platform_fee_bps,
+        platform_treasury: split_state.platform_treasury,
+        distributor,
+        dust_policy,
+        collaborators,
+    });
+    
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(content_id: [u8; 32], platform_fee_bps: u16, collaborators: Vec<Collaborator>, seed: u64)]
+#[instruction(content_id: [u8; 32], platform_fee_bps: u16, collaborators: Vec<CollaboratorShare>, seed: u64)]
 pub struct InitializeSplit<'info> {
     /// Creator who owns the content
     #[account(mut)]
     pub creator: Signer<'info>,
     
-    /// Platform treasury that receives platform fees
-    /// CHECK: Treasury address validated by authority
-    pub platform_treasury: UncheckedAccount<'info>,
+    /// Platform config providing the fee bounds and treasury
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Currency the split accepts (System::id() for SOL, token mint for SPL)
+    /// CHECK: Validated as a token mint unless it is System::id()
+    pub payment_token_mint: UncheckedAccount<'info>,
     
     /// Split state PDA
     #[account(
@@ -72,6 +124,15 @@ pub struct InitializeSplit<'info> {
     )]
     pub split_state: Account<'info, SplitState>,
     
+    /// Vault PDA, funded with its rent-exempt reserve for SOL splits
+    /// CHECK: Vault PDA validated by seeds
+    #[account(
+        mut,
+        seeds = [b"vault", split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
     /// System program
     pub system_program: Program<'info, System>,
 }
@@ -1,5 +1,27 @@
+pub mod initialize_platform_config;
+pub mod update_platform_config;
+pub mod set_platform_paused;
+pub mod transfer_admin;
+pub mod accept_admin;
 pub mod initialize_split;
+pub mod update_split;
 pub mod distribute;
+pub mod hold;
+pub mod release;
+pub mod claim;
+pub mod refund;
+pub mod close_split;
 
+pub use initialize_platform_config::*;
+pub use update_platform_config::*;
+pub use set_platform_paused::*;
+pub use transfer_admin::*;
+pub use accept_admin::*;
 pub use initialize_split::*;
+pub use update_split::*;
 pub use distribute::*;
+pub use hold::*;
+pub use release::*;
+pub use claim::*;
+pub use refund::*;
+pub use close_split::*;
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use crate::utils::{vault_balance, VaultTransfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Return a payment held in the vault to a buyer
+/// Called via CPI from payment escrow program when a purchase is refunded
+/// inside its refund window, before the payment has been released to recipients
+pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+    require!(amount > 0, DistributionError::InsufficientFunds);
+    
+    // Only held payments can be refunded - never balances owed to recipients or stray deposits
+    let split_state = &mut ctx.accounts.split_state;
+    split_state.held_amount = split_state.held_amount
+        .checked_sub(amount)
+        .ok_or(DistributionError::InsufficientFunds)?;
+    
+    let split_state = &ctx.accounts.split_state;
+    let available = vault_balance(
+        split_state,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+    )?
+    .checked_sub(split_state.total_owed()?)
+    .ok_or(DistributionError::InsufficientFunds)?;
+    require!(amount <= available, DistributionError::InsufficientFunds);
+    
+    let split_state_key = ctx.accounts.split_state.key();
+    let vault_bump = ctx.bumps.vault;
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        split_state_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    // Determine if SOL or SPL payment
+    let is_sol_payment = split_state.payment_token_mint == System::id();
+    
+    if is_sol_payment {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        msg!("Refunded {} lamports to {}", amount, ctx.accounts.recipient.key());
+    } else {
+        VaultTransfer {
+            split_state,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+            recipient: ctx.accounts.recipient.key(),
+            recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        }
+        .execute(amount, signer_seeds)?;
+        msg!("Refunded {} tokens to {}", amount, ctx.accounts.recipient.key());
+    }
+    
+    emit_cpi!(PaymentRefunded {
+        split_state: split_state_key,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        held_amount: split_state.held_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    /// Authorized distributor
+    #[account(
+        constraint = authority.key() == split_state.distributor @ DistributionError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Vault holding the funds (SOL or SPL)
+    /// CHECK: Vault PDA validated by seeds
+    #[account(
+        mut,
+        seeds = [b"vault", split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Buyer receiving the refund
+    /// CHECK: Chosen by the distributor, which validates it against the purchase
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL splits)
+    /// CHECK: Validated against the split when the split uses an SPL mint
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Vault token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Recipient token account for SPL splits
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    #[account(mut)]
+    pub recipient_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for SPL splits (SPL Token or Token-2022)
+    /// CHECK: Optional, validated when the split uses an SPL mint
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
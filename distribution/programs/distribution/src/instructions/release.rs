@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{distribute, Distribute};
+use crate::errors::*;
+use crate::events::*;
+
+/// Credit a held payment to the recipients once its refund window has closed
+/// Called via CPI from payment escrow program; otherwise identical to `distribute`
+pub fn release(ctx: Context<Distribute>, amount: u64) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    split_state.held_amount = split_state.held_amount
+        .checked_sub(amount)
+        .ok_or(DistributionError::InsufficientFunds)?;
+    
+    msg!("Held payment released: amount={}", amount);
+    
+    emit_cpi!(PaymentReleased {
+        split_state: split_state.key(),
+        amount,
+        held_amount: split_state.held_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    distribute::distribute(ctx, amount)
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdatePlatformConfig;
+use crate::events::*;
+
+/// Set or clear the platform-wide pause flag (admin only)
+pub fn set_platform_paused(ctx: Context<UpdatePlatformConfig>, paused: bool) -> Result<()> {
+    ctx.accounts.platform_config.paused = paused;
+    
+    msg!("Platform paused: {}", paused);
+    
+    emit_cpi!(PlatformPauseChanged {
+        platform_config: ctx.accounts.platform_config.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdatePlatformConfig;
+use crate::events::*;
+
+/// Nominate a new platform admin (admin only)
+/// The nominee takes over only after calling `accept_admin`; nominating again replaces the nominee
+pub fn transfer_admin(ctx: Context<UpdatePlatformConfig>, new_admin: Pubkey) -> Result<()> {
+    ctx.accounts.platform_config.pending_admin = Some(new_admin);
+    
+    msg!("Platform admin transfer proposed to: {}", new_admin);
+    
+    emit_cpi!(AdminTransferProposed {
+        platform_config: ctx.accounts.platform_config.key(),
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
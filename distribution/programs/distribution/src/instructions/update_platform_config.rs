@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Update the treasury, fee bounds and collaborator limit (admin only)
+/// A new treasury reaches existing splits on their next `distribute` or `update_split`,
+/// together with any platform fee they still owe; until then the old treasury can claim it.
+/// Existing splits keep their fee until the creator updates it within the new bounds
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    settings: PlatformSettings,
+) -> Result<()> {
+    settings.validate()?;
+    
+    ctx.accounts.platform_config.apply_settings(&settings);
+    
+    msg!("Platform config updated: {:?}", settings);
+    
+    emit_cpi!(PlatformConfigUpdated {
+        platform_config: ctx.accounts.platform_config.key(),
+        settings,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    /// Current platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DistributionError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
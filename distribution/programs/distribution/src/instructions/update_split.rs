@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Update the platform fee, collaborators and dust policy of an unlocked split
+/// Any collaborator whose share is reduced (or removed) must co-sign via remaining accounts,
+/// and collaborators with an unclaimed balance cannot be removed; setting `lock` freezes the split permanently
+pub fn update_split(
+    ctx: Context<UpdateSplit>,
+    platform_fee_bps: u16,
+    collaborators: Vec<CollaboratorShare>,
+    dust_policy: DustPolicy,
+    lock: bool,
+) -> Result<()> {
+    // Validate platform fee and collaborator count against the platform policy
+    ctx.accounts.platform_config.validate_split_terms(platform_fee_bps, collaborators.len())?;
+    
+    SplitState::validate_collaborators(&collaborators)?;
+    
+    let split_state = &mut ctx.accounts.split_state;
+    
+    // Every existing collaborator whose share goes down must consent,
+    // and nobody can be removed while they still have a balance to claim
+    for existing in &split_state.collaborators {
+        let new_share = collaborators
+            .iter()
+            .find(|c| c.pubkey == existing.pubkey)
+            .map(|c| c.share_bps);
+        
+        if new_share.is_none() {
+            require!(existing.unclaimed == 0, DistributionError::UnclaimedBalance);
+        }
+        
+        if new_share.unwrap_or(0) < existing.share_bps {
+            require!(
+                ctx.remaining_accounts
+                    .iter()
+                    .any(|account| account.key() == existing.pubkey && account.is_signer),
+                DistributionError::MissingCollaboratorSignature
+            );
+        }
+    }
+    
+    // Carry credited balances over to the new collaborator list
+    let updated: Vec<Collaborator> = collaborators
+        .iter()
+        .map(|c| Collaborator {
+            pubkey: c.pubkey,
+            share_bps: c.share_bps,
+            unclaimed: split_state.collaborators
+                .iter()
+                .find(|existing| existing.pubkey == c.pubkey)
+                .map(|existing| existing.unclaimed)
+                .unwrap_or(0),
+        })
+        .collect();
+    
+    split_state.platform_fee_bps = platform_fee_bps;
+    split_state.platform_treasury = ctx.accounts.platform_config.treasury;
+    split_state.collaborators = updated;
+    split_state.dust_policy = dust_policy;
+    split_state.locked = lock;
+    
+    // Validate total shares don't exceed 100%
+    split_state.validate_shares()?;
+    
+    msg!("Split updated: platform fee {}bps, collaborators: {}, locked: {}",
+        platform_fee_bps, split_state.collaborators.len(), lock);
+    
+    emit_cpi!(SplitUpdated {
+        split_state: split_state.key(),
+        platform_fee_bps,
+        platform_treasury: split_state.platform_treasury,
+        dust_policy,
+        collaborators,
+        locked: lock,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(platform_fee_bps: u16, collaborators: Vec<CollaboratorShare>)]
+pub struct UpdateSplit<'info> {
+    /// Creator who owns the split
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA (resized to fit the new collaborator list)
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::InvalidCreator,
+        constraint = !split_state.locked @ DistributionError::SplitLocked,
+        realloc = SplitState::space(collaborators.len()),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Platform config providing the fee bounds
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: signers for collaborators whose share is reduced
+}
@@ -6,26 +6,69 @@ declare_id!("Czw384wkAHcNT7QpJC4y1DZ7LrKjyqsgTu8gHhsXtUpK");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
+pub mod utils;
 
 use instructions::*;
 
 #[program]
 pub mod distribution {
     use super::*;
-
+    
+    /// Create the platform config singleton (program upgrade authority only)
+    ///
+    /// # Arguments
+    /// * `settings` - Platform treasury, platform fee bounds and collaborator limit
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        settings: state::PlatformSettings,
+    ) -> Result<()> {
+        instructions::initialize_platform_config::initialize_platform_config(ctx, settings)
+    }
+    
+    /// Update the platform treasury, fee bounds and collaborator limit (admin only)
+    /// Existing splits move to the new treasury on their next distribution; their fee is
+    /// only checked against the new bounds when the creator calls `update_split`
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        settings: state::PlatformSettings,
+    ) -> Result<()> {
+        instructions::update_platform_config::update_platform_config(ctx, settings)
+    }
+    
+    /// Set or clear the platform-wide pause flag (admin only)
+    pub fn set_platform_paused(ctx: Context<UpdatePlatformConfig>, paused: bool) -> Result<()> {
+        instructions::set_platform_paused::set_platform_paused(ctx, paused)
+    }
+    
+    /// Nominate a new platform admin (admin only); takes effect once accepted
+    pub fn transfer_admin(ctx: Context<UpdatePlatformConfig>, new_admin: Pubkey) -> Result<()> {
+        instructions::transfer_admin::transfer_admin(ctx, new_admin)
+    }
+    
+    /// Accept a pending admin nomination (nominee only)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+    
     /// Initialize a new split configuration for content revenue sharing
+    /// SOL splits fund their vault with its rent-exempt reserve, paid by the creator
     ///
     /// # Arguments
     /// * `content_id` - 32-byte unique identifier for the content
-    /// * `platform_fee_bps` - Platform fee in basis points (max 1000 = 10%)
+    /// * `platform_fee_bps` - Platform fee in basis points (within the platform config bounds)
     /// * `collaborators` - List of collaborators and their share percentages
     /// * `seed` - Seed for PDA derivation
+    /// * `distributor` - Authority allowed to trigger distributions (e.g. the payment escrow PDA)
+    /// * `dust_policy` - Who receives rounding remainders
     pub fn initialize_split(
         ctx: Context<InitializeSplit>,
         content_id: [u8; 32],
         platform_fee_bps: u16,
-        collaborators: Vec<state::Collaborator>,
+        collaborators: Vec<state::CollaboratorShare>,
         seed: u64,
+        distributor: Pubkey,
+        dust_policy: state::DustPolicy,
     ) -> Result<()> {
         instructions::initialize_split::initialize_split(
             ctx,
@@ -33,18 +76,80 @@ pub mod distribution {
             platform_fee_bps,
             collaborators,
             seed,
+            distributor,
+            dust_policy,
         )
     }
-
-    /// Distribute funds from vault to all recipients according to split configuration
-    /// Typically called via CPI from payment escrow program
+    
+    /// Update the platform fee, collaborators and dust policy of a split (creator only)
+    /// Collaborators whose share is reduced must co-sign via remaining accounts
     ///
     /// # Arguments
-    /// * `amount` - Total amount to distribute
-    pub fn distribute<'info>(
-        ctx: Context<'_, '_, '_, 'info, Distribute<'info>>,
-        amount: u64,
+    /// * `platform_fee_bps` - Platform fee in basis points (within the platform config bounds)
+    /// * `collaborators` - New list of collaborators and their share percentages
+    /// * `dust_policy` - Who receives rounding remainders
+    /// * `lock` - Permanently freeze the split after this update
+    pub fn update_split(
+        ctx: Context<UpdateSplit>,
+        platform_fee_bps: u16,
+        collaborators: Vec<state::CollaboratorShare>,
+        dust_policy: state::DustPolicy,
+        lock: bool,
     ) -> Result<()> {
+        instructions::update_split::update_split(
+            ctx,
+            platform_fee_bps,
+            collaborators,
+            dust_policy,
+            lock,
+        )
+    }
+    
+    /// Credit funds in the vault to all recipients according to split configuration
+    /// Typically called via CPI from payment escrow program; requires the distributor
+    /// Rejected while the platform is paused
+    ///
+    /// # Arguments
+    /// * `amount` - Total amount to distribute
+    pub fn distribute(ctx: Context<Distribute>, amount: u64) -> Result<()> {
         instructions::distribute::distribute(ctx, amount)
     }
+    
+    /// Record a payment in the vault that is held for a refund window (distributor only)
+    /// Held payments can only be credited with `release` or returned with `refund`
+    ///
+    /// # Arguments
+    /// * `amount` - Amount moved into the vault and held
+    pub fn hold(ctx: Context<Hold>, amount: u64) -> Result<()> {
+        instructions::hold::hold(ctx, amount)
+    }
+    
+    /// Credit a held payment to all recipients once its refund window closes (distributor only)
+    /// Rejected while the platform is paused
+    ///
+    /// # Arguments
+    /// * `amount` - Held amount to distribute
+    pub fn release(ctx: Context<Distribute>, amount: u64) -> Result<()> {
+        instructions::release::release(ctx, amount)
+    }
+    
+    /// Withdraw the signer's credited balance (creator, platform treasury or collaborator)
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        instructions::claim::claim(ctx)
+    }
+    
+    /// Return a held payment from the vault to a buyer
+    /// Typically called via CPI from payment escrow program; requires the distributor
+    ///
+    /// # Arguments
+    /// * `amount` - Amount to refund
+    pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+        instructions::refund::refund(ctx, amount)
+    }
+    
+    /// Close a retired split and its vault, returning dust, stray funds and rent to the creator (creator only)
+    /// Every balance must be claimed and no held payments may remain in the vault
+    pub fn close_split(ctx: Context<CloseSplit>) -> Result<()> {
+        instructions::close_split::close_split(ctx)
+    }
 }
@@ -1,3 +1,5 @@
 pub mod split;
+pub mod platform_config;
 
 pub use split::*;
+pub use platform_config::*;
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::errors::DistributionError;
+
+/// Platform Config - singleton holding the platform-wide fee policy and admin
+#[account]
+pub struct PlatformConfig {
+    /// Admin allowed to update the config
+    pub admin: Pubkey,
+    
+    /// Admin nominated by `transfer_admin`, waiting to accept
+    pub pending_admin: Option<Pubkey>,
+    
+    /// Treasury that receives platform fees (existing splits follow it on their next distribution)
+    pub treasury: Pubkey,
+    
+    /// Lowest platform fee a split may charge, in basis points
+    pub min_platform_fee_bps: u16,
+    
+    /// Highest platform fee a split may charge, in basis points
+    pub max_platform_fee_bps: u16,
+    
+    /// Maximum number of collaborators per split
+    pub max_collaborators: u8,
+    
+    /// Whether the platform is paused
+    pub paused: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Option<Pubkey> (1 + 32) + Pubkey (32)
+    /// + u16 (2) + u16 (2) + u8 (1) + bool (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 33 + 32 + 2 + 2 + 1 + 1 + 1;
+    
+    /// PDA seed (one config per deployment)
+    pub const SEED: &'static [u8] = b"platform_config";
+    
+    /// Upper bound for `max_collaborators`, keeping split accounts and claims small
+    pub const MAX_COLLABORATORS_LIMIT: u8 = 32;
+    
+    /// Apply settings from `initialize_platform_config` or `update_platform_config`
+    pub fn apply_settings(&mut self, settings: &PlatformSettings) {
+        self.treasury = settings.treasury;
+        self.min_platform_fee_bps = settings.min_platform_fee_bps;
+        self.max_platform_fee_bps = settings.max_platform_fee_bps;
+        self.max_collaborators = settings.max_collaborators;
+    }
+    
+    /// Validate a split's platform fee and collaborator count against the platform policy
+    pub fn validate_split_terms(&self, platform_fee_bps: u16, num_collaborators: usize) -> Result<()> {
+        require!(
+            (self.min_platform_fee_bps..=self.max_platform_fee_bps).contains(&platform_fee_bps),
+            DistributionError::InvalidPlatformFee
+        );
+        require!(
+            num_collaborators <= self.max_collaborators as usize,
+            DistributionError::TooManyCollaborators
+        );
+        
+        Ok(())
+    }
+}
+
+/// Fee policy and treasury chosen by the platform admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PlatformSettings {
+    /// Treasury that receives platform fees (existing splits follow it on their next distribution)
+    pub treasury: Pubkey,
+    /// Lowest platform fee a split may charge, in basis points
+    pub min_platform_fee_bps: u16,
+    /// Highest platform fee a split may charge, in basis points
+    pub max_platform_fee_bps: u16,
+    /// Maximum number of collaborators per split
+    pub max_collaborators: u8,
+}
+
+impl PlatformSettings {
+    /// Check the fee bounds and collaborator limit
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_platform_fee_bps <= self.max_platform_fee_bps
+                && self.max_platform_fee_bps <= 10000,
+            DistributionError::InvalidPlatformFee
+        );
+        require!(
+            self.max_collaborators <= PlatformConfig::MAX_COLLABORATORS_LIMIT,
+            DistributionError::TooManyCollaborators
+        );
+        
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::DistributionError;
 
 /// Split State - defines how revenue is distributed for a specific content
 #[account]
@@ -12,10 +13,35 @@ pub struct SplitState {
     /// Platform fee in basis points (e.g., 250 = 2.5%)
     pub platform_fee_bps: u16,
     
-    /// Platform treasury address
+    /// Platform treasury address, refreshed from the platform config by `distribute` and `update_split`
     pub platform_treasury: Pubkey,
     
-    /// List of collaborators and their shares
+    /// Authority allowed to call `distribute`, `hold`, `release` and `refund` (e.g. the payment escrow PDA)
+    pub distributor: Pubkey,
+    
+    /// Whether the split is permanently frozen against updates
+    pub locked: bool,
+    
+    /// Currency this split accepts (System::id() for SOL, token mint for SPL)
+    pub payment_token_mint: Pubkey,
+    
+    /// Creator's credited balance waiting to be claimed
+    pub creator_unclaimed: u64,
+    
+    /// Platform's credited balance waiting to be claimed
+    pub platform_unclaimed: u64,
+    
+    /// How rounding remainders are assigned
+    pub dust_policy: DustPolicy,
+    
+    /// Rounding remainder held in the vault for the next distribution (CarryForward)
+    pub carried_dust: u64,
+    
+    /// Payments in the vault waiting out a refund window, recorded by `hold`
+    /// Neither credited nor claimable until released, and the only funds `refund` may return
+    pub held_amount: u64,
+    
+    /// List of collaborators, their shares and credited balances
     pub collaborators: Vec<Collaborator>,
     
     /// Timestamp of last distribution
@@ -31,11 +57,12 @@ pub struct SplitState {
 impl SplitState {
     /// Base size without collaborators
     /// Discriminator (8) + [u8; 32] (32) + Pubkey (32) + u16 (2) 
-    /// + Pubkey (32) + Vec length (4) + i64 (8) + u64 (8) + u8 (1)
-    pub const BASE_LEN: usize = 8 + 32 + 32 + 2 + 32 + 4 + 8 + 8 + 1;
+    /// + Pubkey (32) + Pubkey (32) + bool (1) + Pubkey (32) + u64 (8) + u64 (8) 
+    /// + DustPolicy (1) + u64 (8) + u64 (8) + Vec length (4) + i64 (8) + u64 (8) + u8 (1)
+    pub const BASE_LEN: usize = 8 + 32 + 32 + 2 + 32 + 32 + 1 + 32 + 8 + 8 + 1 + 8 + 8 + 4 + 8 + 8 + 1;
     
-    /// Size per collaborator: Pubkey (32) + u16 (2)
-    pub const COLLABORATOR_LEN: usize = 32 + 2;
+    /// Size per collaborator: Pubkey (32) + u16 (2) + u64 (8)
+    pub const COLLABORATOR_LEN: usize = 32 + 2 + 8;
     
     /// Calculate space needed for a given number of collaborators
     pub fn space(num_collaborators: usize) -> usize {
@@ -45,6 +72,18 @@ impl SplitState {
     /// PDA seed prefix
     pub const SEED_PREFIX: &'static [u8] = b"split";
     
+    /// Reject collaborator lists that name the same wallet twice
+    pub fn validate_collaborators(collaborators: &[CollaboratorShare]) -> Result<()> {
+        for (i, collaborator) in collaborators.iter().enumerate() {
+            require!(
+                !collaborators[..i].iter().any(|c| c.pubkey == collaborator.pubkey),
+                DistributionError::InvalidCollaborator
+            );
+        }
+        
+        Ok(())
+    }
+    
     /// Validate that total basis points don't exceed 10000 (100%)
     pub fn validate_shares(&self) -> Result<()> {
         let total_collab_bps: u16 = self.collaborators
@@ -64,52 +103,146 @@ impl SplitState {
         Ok(())
     }
     
-    /// Calculate creator's share after platform fee and collaborator shares
-    pub fn calculate_creator_share(&self, total_amount: u64) -> Result<u64> {
-        let platform_amount = self.calculate_platform_fee(total_amount)?;
+    /// Split `amount` (plus any carried dust) into line items
+    ///
+    /// Every line item is floored from its basis points, with the creator holding
+    /// whatever basis points are left after the platform fee and collaborators.
+    /// The rounding remainder is then assigned by `dust_policy`, so
+    /// `platform + creator + sum(collaborators) + carried_dust == amount + self.carried_dust`.
+    pub fn compute_allocation(&self, amount: u64) -> Result<Allocation> {
+        let distributable = amount
+            .checked_add(self.carried_dust)
+            .ok_or(DistributionError::NumericalOverflow)?;
         
-        let mut remaining = total_amount
-            .checked_sub(platform_amount)
+        let collab_bps: u16 = self.collaborators
+            .iter()
+            .map(|c| c.share_bps)
+            .try_fold(0u16, |total, bps| total.checked_add(bps))
             .ok_or(DistributionError::NumericalOverflow)?;
+        let creator_bps = 10000u16
+            .checked_sub(self.platform_fee_bps)
+            .and_then(|bps| bps.checked_sub(collab_bps))
+            .ok_or(DistributionError::InvalidShareDistribution)?;
         
-        // Subtract collaborator shares
-        for collaborator in &self.collaborators {
-            let collab_amount = total_amount
-                .checked_mul(collaborator.share_bps as u64)
-                .ok_or(DistributionError::NumericalOverflow)?
-                .checked_div(10000)
-                .ok_or(DistributionError::NumericalOverflow)?;
-            
-            remaining = remaining
-                .checked_sub(collab_amount)
+        let mut allocation = Allocation {
+            platform: Self::share_of(distributable, self.platform_fee_bps),
+            creator: Self::share_of(distributable, creator_bps),
+            collaborators: self.collaborators
+                .iter()
+                .map(|c| Self::share_of(distributable, c.share_bps))
+                .collect(),
+            carried_dust: 0,
+        };
+        
+        // Each floor loses less than one unit, so dust is below the number of line items
+        let dust = distributable - allocation.total();
+        
+        match self.dust_policy {
+            DustPolicy::Creator => allocation.creator += dust,
+            DustPolicy::LargestShare => {
+                // Ties go to the creator, then the platform, then collaborators in order
+                let mut largest_bps = creator_bps;
+                let mut target: &mut u64 = &mut allocation.creator;
+                if self.platform_fee_bps > largest_bps {
+                    largest_bps = self.platform_fee_bps;
+                    target = &mut allocation.platform;
+                }
+                for (collaborator, collab_amount) in self.collaborators
+                    .iter()
+                    .zip(allocation.collaborators.iter_mut())
+                {
+                    if collaborator.share_bps > largest_bps {
+                        largest_bps = collaborator.share_bps;
+                        target = collab_amount;
+                    }
+                }
+                *target += dust;
+            }
+            DustPolicy::CarryForward => allocation.carried_dust = dust,
+        }
+        
+        Ok(allocation)
+    }
+    
+    /// Floor of `amount * bps / 10000` without intermediate overflow
+    fn share_of(amount: u64, bps: u16) -> u64 {
+        (amount as u128 * bps as u128 / 10000) as u64
+    }
+    
+    /// Credit `amount` to the creator, platform and collaborators according to the split
+    /// Returns the allocation that was credited
+    pub fn credit(&mut self, amount: u64) -> Result<Allocation> {
+        let allocation = self.compute_allocation(amount)?;
+        
+        for (collaborator, collab_amount) in self.collaborators
+            .iter_mut()
+            .zip(allocation.collaborators.iter())
+        {
+            collaborator.unclaimed = collaborator.unclaimed
+                .checked_add(*collab_amount)
                 .ok_or(DistributionError::NumericalOverflow)?;
         }
         
-        Ok(remaining)
+        self.platform_unclaimed = self.platform_unclaimed
+            .checked_add(allocation.platform)
+            .ok_or(DistributionError::NumericalOverflow)?;
+        self.creator_unclaimed = self.creator_unclaimed
+            .checked_add(allocation.creator)
+            .ok_or(DistributionError::NumericalOverflow)?;
+        self.carried_dust = allocation.carried_dust;
+        
+        Ok(allocation)
+    }
+    
+    /// Total owed to recipients: credited balances not yet claimed plus carried dust
+    pub fn total_owed(&self) -> Result<u64> {
+        self.collaborators
+            .iter()
+            .try_fold(
+                self.creator_unclaimed
+                    .checked_add(self.platform_unclaimed)
+                    .and_then(|total| total.checked_add(self.carried_dust))
+                    .ok_or(DistributionError::NumericalOverflow)?,
+                |total, c| total.checked_add(c.unclaimed),
+            )
+            .ok_or(DistributionError::NumericalOverflow.into())
     }
     
-    /// Calculate platform fee amount
-    pub fn calculate_platform_fee(&self, total_amount: u64) -> Result<u64> {
-        total_amount
-            .checked_mul(self.platform_fee_bps as u64)
-            .ok_or(DistributionError::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(DistributionError::NumericalOverflow)
-            .map_err(|_| DistributionError::NumericalOverflow.into())
+    /// Everything the vault must keep back: balances owed to recipients plus held payments
+    pub fn total_committed(&self) -> Result<u64> {
+        self.total_owed()?
+            .checked_add(self.held_amount)
+            .ok_or(DistributionError::NumericalOverflow.into())
     }
     
-    /// Calculate collaborator's share amount
-    pub fn calculate_collaborator_share(&self, total_amount: u64, share_bps: u16) -> Result<u64> {
-        total_amount
-            .checked_mul(share_bps as u64)
-            .ok_or(DistributionError::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(DistributionError::NumericalOverflow)
-            .map_err(|_| DistributionError::NumericalOverflow.into())
+    /// Zero and return every balance credited to `recipient`
+    pub fn take_unclaimed(&mut self, recipient: &Pubkey) -> Result<u64> {
+        let mut amount: u64 = 0;
+        
+        if *recipient == self.creator {
+            amount = amount
+                .checked_add(self.creator_unclaimed)
+                .ok_or(DistributionError::NumericalOverflow)?;
+            self.creator_unclaimed = 0;
+        }
+        if *recipient == self.platform_treasury {
+            amount = amount
+                .checked_add(self.platform_unclaimed)
+                .ok_or(DistributionError::NumericalOverflow)?;
+            self.platform_unclaimed = 0;
+        }
+        for collaborator in self.collaborators.iter_mut().filter(|c| c.pubkey == *recipient) {
+            amount = amount
+                .checked_add(collaborator.unclaimed)
+                .ok_or(DistributionError::NumericalOverflow)?;
+            collaborator.unclaimed = 0;
+        }
+        
+        Ok(amount)
     }
 }
 
-/// Collaborator with their revenue share
+/// Collaborator with their revenue share and credited balance
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct Collaborator {
     /// Collaborator's public key
@@ -117,6 +250,197 @@ pub struct Collaborator {
     
     /// Share in basis points (e.g., 500 = 5%)
     pub share_bps: u16,
+    
+    /// Credited balance waiting to be claimed
+    pub unclaimed: u64,
 }
 
-use crate::errors::DistributionError;
+/// How rounding remainders ("dust") are assigned when a payment is split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DustPolicy {
+    /// The creator receives the remainder
+    Creator,
+    /// The recipient with the largest share receives the remainder
+    /// (ties go to the creator, then the platform, then collaborators in order)
+    LargestShare,
+    /// The remainder stays in the vault and is added to the next distribution
+    CarryForward,
+}
+
+/// Line items for one distribution, produced by `SplitState::compute_allocation`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    /// Platform fee
+    pub platform: u64,
+    
+    /// Creator's share
+    pub creator: u64,
+    
+    /// Collaborator shares, in split order
+    pub collaborators: Vec<u64>,
+    
+    /// Remainder held back for the next distribution
+    pub carried_dust: u64,
+}
+
+impl Allocation {
+    /// Sum of all line items, excluding carried dust
+    pub fn total(&self) -> u64 {
+        self.platform + self.creator + self.collaborators.iter().sum::<u64>()
+    }
+}
+
+/// Collaborator share passed to `initialize_split` and `update_split`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CollaboratorShare {
+    /// Collaborator's public key
+    pub pubkey: Pubkey,
+    
+    /// Share in basis points (e.g., 500 = 5%)
+    pub share_bps: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    
+    fn split(platform_fee_bps: u16, shares: &[u16], dust_policy: DustPolicy, carried_dust: u64) -> SplitState {
+        SplitState {
+            content_id: [0; 32],
+            creator: Pubkey::new_unique(),
+            platform_fee_bps,
+            platform_treasury: Pubkey::new_unique(),
+            distributor: Pubkey::new_unique(),
+            locked: false,
+            payment_token_mint: Pubkey::default(),
+            creator_unclaimed: 0,
+            platform_unclaimed: 0,
+            dust_policy,
+            carried_dust,
+            held_amount: 0,
+            collaborators: shares
+                .iter()
+                .map(|&share_bps| Collaborator {
+                    pubkey: Pubkey::new_unique(),
+                    share_bps,
+                    unclaimed: 0,
+                })
+                .collect(),
+            last_distributed_ts: 0,
+            seed: 0,
+            bump: 0,
+        }
+    }
+    
+    fn dust_policy() -> impl Strategy<Value = DustPolicy> {
+        prop_oneof![
+            Just(DustPolicy::Creator),
+            Just(DustPolicy::LargestShare),
+            Just(DustPolicy::CarryForward),
+        ]
+    }
+    
+    /// Platform fee and up to 10 collaborator shares whose total never exceeds 100%
+    fn shares() -> impl Strategy<Value = (u16, Vec<u16>)> {
+        (0u16..=1000, prop::collection::vec(0u16..=10000, 0..=10)).prop_map(|(platform, raw)| {
+            let mut remaining = 10000 - platform;
+            let shares = raw
+                .into_iter()
+                .map(|bps| {
+                    let share = bps.min(remaining);
+                    remaining -= share;
+                    share
+                })
+                .collect();
+            (platform, shares)
+        })
+    }
+    
+    proptest! {
+        #[test]
+        fn allocation_conserves_amount(
+            (platform, shares) in shares(),
+            policy in dust_policy(),
+            amount in any::<u64>(),
+            carried in 0u64..12,
+        ) {
+            prop_assume!(amount.checked_add(carried).is_some());
+            let state = split(platform, &shares, policy, carried);
+            let allocation = state.compute_allocation(amount).unwrap();
+            
+            prop_assert_eq!(
+                allocation.total() as u128 + allocation.carried_dust as u128,
+                amount as u128 + carried as u128
+            );
+            prop_assert_eq!(allocation.collaborators.len(), shares.len());
+        }
+        
+        #[test]
+        fn allocation_pays_at_least_each_floor(
+            (platform, shares) in shares(),
+            policy in dust_policy(),
+            amount in any::<u64>(),
+        ) {
+            let state = split(platform, &shares, policy, 0);
+            let allocation = state.compute_allocation(amount).unwrap();
+            
+            prop_assert!(allocation.platform >= SplitState::share_of(amount, platform));
+            for (paid, bps) in allocation.collaborators.iter().zip(shares.iter()) {
+                prop_assert!(*paid >= SplitState::share_of(amount, *bps));
+            }
+            // Dust never exceeds one unit per line item
+            prop_assert!(allocation.carried_dust < shares.len() as u64 + 2);
+        }
+        
+        #[test]
+        fn only_carry_forward_holds_dust(
+            (platform, shares) in shares(),
+            policy in dust_policy(),
+            amount in any::<u64>(),
+        ) {
+            let state = split(platform, &shares, policy, 0);
+            let allocation = state.compute_allocation(amount).unwrap();
+            
+            if policy != DustPolicy::CarryForward {
+                prop_assert_eq!(allocation.carried_dust, 0);
+                prop_assert_eq!(allocation.total(), amount);
+            }
+        }
+    }
+    
+    #[test]
+    fn creator_policy_gives_dust_to_creator() {
+        let state = split(250, &[3333, 3333], DustPolicy::Creator, 0);
+        let allocation = state.compute_allocation(10).unwrap();
+        
+        assert_eq!(allocation.platform, 0);
+        assert_eq!(allocation.collaborators, vec![3, 3]);
+        assert_eq!(allocation.creator, 4);
+    }
+    
+    #[test]
+    fn largest_share_policy_gives_dust_to_largest_collaborator() {
+        let state = split(250, &[1000, 6000], DustPolicy::LargestShare, 0);
+        let allocation = state.compute_allocation(7).unwrap();
+        
+        // Floors: platform 0, collaborators 0 and 4, creator 1 - dust of 2 goes to the 60% share
+        assert_eq!(allocation.collaborators, vec![0, 6]);
+        assert_eq!(allocation.creator, 1);
+        assert_eq!(allocation.total(), 7);
+    }
+    
+    #[test]
+    fn carry_forward_policy_pays_dust_on_a_later_distribution() {
+        let mut state = split(0, &[5000], DustPolicy::CarryForward, 0);
+        
+        state.credit(1).unwrap();
+        assert_eq!(state.carried_dust, 1);
+        assert_eq!(state.total_owed().unwrap(), 1);
+        
+        state.credit(1).unwrap();
+        assert_eq!(state.carried_dust, 0);
+        assert_eq!(state.creator_unclaimed, 1);
+        assert_eq!(state.collaborators[0].unclaimed, 1);
+    }
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+    transfer_hook::TransferHook,
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{Account as SplAccount, Mint as SplMint};
+use anchor_spl::token_interface::{self, HarvestWithheldTokensToMint, Mint, TokenAccount, TransferChecked};
+use crate::state::SplitState;
+use crate::errors::*;
+
+/// Move transfer fees withheld in `account` to the mint so the account can be closed
+/// No-op for legacy mints and mints without the transfer fee extension
+pub(crate) fn harvest_withheld_fees<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+) -> Result<()> {
+    if token_program.key() != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    require!(
+        *mint.owner == anchor_spl::token_2022::ID,
+        DistributionError::InvalidPaymentMint
+    );
+    
+    let withheld = {
+        let data = account.try_borrow_data()?;
+        let state = StateWithExtensions::<SplAccount>::unpack(&data)?;
+        state
+            .get_extension::<TransferFeeAmount>()
+            .map(|fee| u64::from(fee.withheld_amount))
+            .unwrap_or(0)
+    };
+    let has_fee_config = {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+        state.get_extension::<TransferFeeConfig>().is_ok()
+    };
+    
+    if withheld > 0 && has_fee_config {
+        token_interface::harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token_program.clone(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            vec![account.clone()],
+        )?;
+    }
+    
+    Ok(())
+}
+
+/// Amount held by the split vault in the split's currency
+/// SOL balances exclude the vault's rent-exempt reserve; SPL splits must pass the vault's associated token account
+pub(crate) fn vault_balance(
+    split_state: &SplitState,
+    vault: &AccountInfo,
+    vault_token_account: &AccountInfo,
+) -> Result<u64> {
+    if split_state.payment_token_mint == System::id() {
+        return Ok(vault.lamports().saturating_sub(sol_vault_reserve()?));
+    }
+    
+    let token_account = validate_vault_token_account(vault_token_account, &vault.key(), &split_state.payment_token_mint)?;
+    
+    Ok(token_account.amount)
+}
+
+/// Lamports kept in a SOL split vault from `initialize_split` until `close_split`
+/// (the rent-exempt minimum of a data-less account)
+pub(crate) fn sol_vault_reserve() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+/// Check that a token account (SPL Token or Token-2022) belongs to `owner` and holds `mint`
+pub(crate) fn validate_token_account(account: &AccountInfo, owner: &Pubkey, mint: &Pubkey) -> Result<TokenAccount> {
+    require!(
+        is_token_program(account.owner),
+        DistributionError::InvalidTokenAccount
+    );
+    
+    let data = account.try_borrow_data()?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| DistributionError::InvalidTokenAccount)?;
+    
+    require!(
+        token_account.owner == *owner,
+        DistributionError::InvalidRecipient
+    );
+    require!(
+        token_account.mint == *mint,
+        DistributionError::InvalidTokenAccount
+    );
+    
+    Ok(token_account)
+}
+
+/// Check that `account` is `vault`'s associated token account for `mint`
+/// The token program is read from the account owner, so Token-2022 vaults resolve too
+pub(crate) fn validate_vault_token_account(account: &AccountInfo, vault: &Pubkey, mint: &Pubkey) -> Result<TokenAccount> {
+    require!(
+        account.key() == get_associated_token_address_with_program_id(vault, mint, account.owner),
+        DistributionError::InvalidVault
+    );
+    
+    validate_token_account(account, vault, mint).map_err(|_| DistributionError::InvalidVault.into())
+}
+
+/// Whether `program_id` is the legacy token program or Token-2022
+pub(crate) fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == anchor_spl::token::ID || *program_id == anchor_spl::token_2022::ID
+}
+
+/// Check that an SPL split's mint is usable as a payment currency
+/// Mints with a transfer hook are rejected since their transfers need extra accounts
+pub(crate) fn validate_payment_mint(mint: &AccountInfo) -> Result<()> {
+    require!(
+        is_token_program(mint.owner),
+        DistributionError::InvalidPaymentMint
+    );
+    
+    let data = mint.try_borrow_data()?;
+    Mint::try_deserialize(&mut &data[..])
+        .map_err(|_| DistributionError::InvalidPaymentMint)?;
+    
+    if *mint.owner == anchor_spl::token_2022::ID {
+        let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+        if let Ok(hook) = state.get_extension::<TransferHook>() {
+            require!(
+                Option::<Pubkey>::from(hook.program_id).is_none(),
+                DistributionError::UnsupportedMintExtension
+            );
+        }
+    }
+    
+    Ok(())
+}
+
+/// Accounts used to send tokens out of the split vault
+pub(crate) struct VaultTransfer<'a, 'info> {
+    pub split_state: &'a SplitState,
+    pub token_program: AccountInfo<'info>,
+    pub payment_token_mint: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub vault_token_account: AccountInfo<'info>,
+    pub recipient: Pubkey,
+    pub recipient_token_account: AccountInfo<'info>,
+}
+
+impl<'a, 'info> VaultTransfer<'a, 'info> {
+    /// Send `amount` tokens to the recipient with `transfer_checked`
+    /// Validates the token program, mint and both token accounts first
+    pub fn execute(self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        require!(
+            is_token_program(&self.token_program.key()),
+            DistributionError::InvalidTokenProgram
+        );
+        require!(
+            self.payment_token_mint.key() == self.split_state.payment_token_mint
+                && *self.payment_token_mint.owner == self.token_program.key(),
+            DistributionError::InvalidPaymentMint
+        );
+        require!(
+            *self.vault_token_account.owner == self.token_program.key(),
+            DistributionError::InvalidVault
+        );
+        
+        // Validate vault and recipient token accounts
+        let mint = self.split_state.payment_token_mint;
+        validate_vault_token_account(&self.vault_token_account, &self.vault.key(), &mint)?;
+        validate_token_account(&self.recipient_token_account, &self.recipient, &mint)?;
+        
+        let decimals = {
+            let data = self.payment_token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])
+                .map_err(|_| DistributionError::InvalidPaymentMint)?
+                .decimals
+        };
+        
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program,
+                TransferChecked {
+                    from: self.vault_token_account,
+                    mint: self.payment_token_mint,
+                    to: self.recipient_token_account,
+                    authority: self.vault,
+                },
+                signer_seeds,
+            ),
+            amount,
+            decimals,
+        )
+    }
+}
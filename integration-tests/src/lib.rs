@@ -0,0 +1,498 @@
+//! In-process test harness for the payment escrow, access mint and distribution programs
+//!
+//! Loads the three compiled programs into LiteSVM so the cross-program purchase flow runs
+//! offline. Build them first with `anchor build` in `access-mint/`, `distribution/` and
+//! `payment-escrow/`, and dump the Metaplex token metadata program to
+//! `access-mint/tests/fixtures/mpl_token_metadata.so` (the same fixture the Anchor tests use):
+//!
+//! ```text
+//! scripts/dump-fixtures.sh
+//! ```
+
+use std::path::PathBuf;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig};
+use spl_token_2022::extension::transfer_hook::TransferHook;
+use spl_token_2022::extension::{BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions, StateWithExtensionsMut};
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use ownmark_sdk::access_mint::state::{AccessConfig, AccessTokenMetadata, TransferMode};
+use ownmark_sdk::accounts::{AccessMintState, Listing, PlatformConfig};
+use ownmark_sdk::distribution::state::{CollaboratorShare, DustPolicy};
+use ownmark_sdk::payment_escrow::state::ListingConfig;
+use ownmark_sdk::{decode_error, instructions, pda, PaymentMode, Purchase};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+/// Platform fee bounds written to the platform config
+pub const MIN_PLATFORM_FEE_BPS: u16 = 100;
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+/// SVM with all three programs loaded and the platform config in place
+pub struct TestEnv {
+    pub svm: LiteSVM,
+    
+    /// Pays every transaction fee, so the balances of the actors only move by what the programs transfer
+    pub payer: Keypair,
+    
+    /// Platform admin
+    pub admin: Keypair,
+    
+    /// Platform treasury receiving the platform fee of every split
+    pub treasury: Keypair,
+}
+
+impl TestEnv {
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        for (program_id, path) in [
+            (ownmark_sdk::access_mint::ID, deployed_program("access-mint", "access_mint")),
+            (ownmark_sdk::distribution::ID, deployed_program("distribution", "distribution")),
+            (ownmark_sdk::payment_escrow::ID, deployed_program("payment-escrow", "payment_escrow")),
+            (
+                anchor_spl::metadata::mpl_token_metadata::ID,
+                workspace_dir().join("access-mint/tests/fixtures/mpl_token_metadata.so"),
+            ),
+        ] {
+            svm.add_program_from_file(program_id, &path)
+                .unwrap_or_else(|err| panic!("failed to load {}: {err}", path.display()));
+        }
+        
+        let mut env = Self {
+            svm,
+            payer: Keypair::new(),
+            admin: Keypair::new(),
+            treasury: Keypair::new(),
+        };
+        env.svm
+            .airdrop(&env.payer.pubkey(), 1_000 * LAMPORTS_PER_SOL)
+            .expect("airdrop to payer");
+        
+        // initialize_platform_config needs the distribution program's upgrade authority,
+        // which a program loaded from a file does not have, so write the account directly
+        let (platform_config, bump) = pda::platform_config();
+        let config = PlatformConfig {
+            admin: env.admin.pubkey(),
+            pending_admin: None,
+            treasury: env.treasury.pubkey(),
+            min_platform_fee_bps: MIN_PLATFORM_FEE_BPS,
+            max_platform_fee_bps: MAX_PLATFORM_FEE_BPS,
+            max_collaborators: 8,
+            paused: false,
+            bump,
+        };
+        env.set_program_account(platform_config, ownmark_sdk::distribution::ID, &config);
+        
+        env
+    }
+    
+    /// New keypair holding 10 SOL
+    pub fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.svm
+            .airdrop(&keypair.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("airdrop");
+        keypair
+    }
+    
+    /// Send `instructions` in one transaction paid by [`TestEnv::payer`] and signed by `signers`
+    /// The blockhash is expired afterwards so an identical transaction can be sent again
+    #[allow(clippy::result_large_err)]
+    pub fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TransactionResult {
+        let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        all_instructions.extend_from_slice(instructions);
+        
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        
+        let transaction = Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(transaction);
+        self.svm.expire_blockhash();
+        result
+    }
+    
+    /// Fetch and decode a program account
+    #[track_caller]
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self
+            .svm
+            .get_account(address)
+            .unwrap_or_else(|| panic!("account {address} does not exist"));
+        T::try_deserialize(&mut &account.data[..]).expect("decode account")
+    }
+    
+    /// Write a program account without going through its program
+    pub fn set_program_account<T: AccountSerialize>(&mut self, address: Pubkey, owner: Pubkey, value: &T) {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).expect("serialize account");
+        self.set_raw_account(address, owner, data);
+    }
+    
+    fn set_raw_account(&mut self, address: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(address, account).expect("set account");
+    }
+    
+    /// Balance of `owner` in the payment currency: lamports for SOL, or its associated token account
+    pub fn balance(&self, owner: &Pubkey, payment: PaymentMode) -> u64 {
+        match payment {
+            PaymentMode::Sol => self.svm.get_balance(owner).unwrap_or(0),
+            PaymentMode::Spl { .. } => self.token_balance(&payment.token_account(owner)),
+        }
+    }
+    
+    /// Lamports a SOL split vault keeps from `initialize_split` until `close_split`
+    pub fn split_vault_reserve(&self) -> u64 {
+        self.svm.minimum_balance_for_rent_exemption(0)
+    }
+    
+    /// Amount held by an SPL Token or Token-2022 account (0 if it does not exist)
+    pub fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        self.svm
+            .get_account(token_account)
+            .map(|account| {
+                StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+                    .expect("decode token account")
+                    .base
+                    .amount
+            })
+            .unwrap_or(0)
+    }
+    
+    /// Transfer fees withheld in a Token-2022 account (0 if it does not exist or has no fee extension)
+    pub fn withheld_fees(&self, token_account: &Pubkey) -> u64 {
+        self.svm
+            .get_account(token_account)
+            .and_then(|account| {
+                let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+                    .expect("decode token account");
+                state
+                    .get_extension::<TransferFeeAmount>()
+                    .map(|fees| u64::from(fees.withheld_amount))
+                    .ok()
+            })
+            .unwrap_or(0)
+    }
+    
+    /// Move the clock forward by `secs`
+    pub fn warp(&mut self, secs: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += secs;
+        self.svm.set_sysvar(&clock);
+    }
+    
+    /// Create an SPL Token payment mint with 6 decimals
+    pub fn create_payment_mint(&mut self, mint_authority: &Pubkey) -> PaymentMode {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(*mint_authority),
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_raw_account(mint, spl_token::ID, data);
+        
+        PaymentMode::Spl {
+            mint,
+            token_program: spl_token::ID,
+        }
+    }
+    
+    /// Create a Token-2022 payment mint with 6 decimals that withholds `fee_bps` of every transfer
+    pub fn create_transfer_fee_mint(&mut self, mint_authority: &Pubkey, fee_bps: u16) -> PaymentMode {
+        self.create_token_2022_mint(mint_authority, ExtensionType::TransferFeeConfig, |state| {
+            let fee = TransferFee {
+                epoch: 0.into(),
+                maximum_fee: u64::MAX.into(),
+                transfer_fee_basis_points: fee_bps.into(),
+            };
+            let config = state
+                .init_extension::<TransferFeeConfig>(true)
+                .expect("transfer fee extension");
+            config.older_transfer_fee = fee;
+            config.newer_transfer_fee = fee;
+        })
+    }
+    
+    /// Create a Token-2022 payment mint with 6 decimals whose transfers call `hook_program`
+    pub fn create_transfer_hook_mint(&mut self, mint_authority: &Pubkey, hook_program: Pubkey) -> PaymentMode {
+        self.create_token_2022_mint(mint_authority, ExtensionType::TransferHook, |state| {
+            state
+                .init_extension::<TransferHook>(true)
+                .expect("transfer hook extension")
+                .program_id = Some(hook_program).try_into().expect("hook program id");
+        })
+    }
+    
+    fn create_token_2022_mint(
+        &mut self,
+        mint_authority: &Pubkey,
+        extension: ExtensionType,
+        init_extension: impl FnOnce(&mut StateWithExtensionsMut<spl_token_2022::state::Mint>),
+    ) -> PaymentMode {
+        let mint = Pubkey::new_unique();
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[extension])
+            .expect("mint length");
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .expect("uninitialized mint");
+        init_extension(&mut state);
+        state.base = spl_token_2022::state::Mint {
+            mint_authority: COption::Some(*mint_authority),
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        state.pack_base();
+        state.init_account_type().expect("mint account type");
+        self.set_raw_account(mint, spl_token_2022::ID, data);
+        
+        PaymentMode::Spl {
+            mint,
+            token_program: spl_token_2022::ID,
+        }
+    }
+    
+    /// Create the associated token account of `owner` (a wallet or a PDA) if it does not exist
+    pub fn create_token_account(&mut self, owner: &Pubkey, payment: PaymentMode) -> Pubkey {
+        let instruction = instructions::create_token_account(self.payer.pubkey(), *owner, payment)
+            .expect("SOL payments have no token accounts");
+        self.send(&[instruction], &[]).expect("create token account");
+        payment.token_account(owner)
+    }
+    
+    /// Mint `amount` payment tokens to `owner`'s associated token account
+    pub fn mint_payment_tokens(&mut self, payment: PaymentMode, mint_authority: &Keypair, owner: &Pubkey, amount: u64) {
+        let token_account = self.create_token_account(owner, payment);
+        let instruction = spl_token_2022::instruction::mint_to(
+            &payment.token_program(),
+            &payment.mint(),
+            &token_account,
+            &mint_authority.pubkey(),
+            &[],
+            amount,
+        )
+        .expect("mint_to instruction");
+        self.send(&[instruction], &[mint_authority]).expect("mint payment tokens");
+    }
+    
+    /// Create a product the way a creator does: initialize_mint, initialize_split with the split vault's
+    /// token account, then create_listing
+    /// The escrow authority PDA is both the access minter and the split distributor
+    pub fn create_product(&mut self, creator: &Keypair, terms: &ProductTerms) -> Product {
+        let escrow_authority = pda::escrow_authority().0;
+        let mint = Keypair::new();
+        let access_mint_state = pda::access_mint_state(&creator.pubkey(), &terms.content_id, terms.seed).0;
+        let split_state = pda::split(&creator.pubkey(), &terms.content_id, terms.seed).0;
+        
+        self.send(
+            &[instructions::initialize_mint(
+                creator.pubkey(),
+                mint.pubkey(),
+                terms.content_id,
+                terms.seed,
+                escrow_authority,
+                AccessTokenMetadata {
+                    name: "Access Pass".to_string(),
+                    symbol: "PASS".to_string(),
+                    uri: "https://example.com/pass.json".to_string(),
+                },
+                AccessConfig {
+                    transfer_mode: terms.transfer_mode,
+                    access_duration_secs: 0,
+                    max_supply: terms.max_supply,
+                },
+            )],
+            &[creator, &mint],
+        )
+        .expect("initialize_mint");
+        
+        let mut transaction = vec![instructions::initialize_split(
+            creator.pubkey(),
+            terms.content_id,
+            terms.platform_fee_bps,
+            terms.collaborators.clone(),
+            terms.seed,
+            escrow_authority,
+            DustPolicy::Creator,
+            terms.payment.mint(),
+        )];
+        // SPL splits receive payments in the vault's associated token account
+        transaction.extend(instructions::create_token_account(
+            creator.pubkey(),
+            pda::split_vault(&split_state).0,
+            terms.payment,
+        ));
+        self.send(&transaction, &[creator]).expect("initialize_split");
+        
+        self.send(
+            &[instructions::create_listing(
+                creator.pubkey(),
+                terms.content_id,
+                terms.price,
+                terms.payment,
+                ListingConfig {
+                    requires_approval: false,
+                    refund_window_secs: terms.refund_window_secs,
+                    sale_start_ts: 0,
+                    sale_end_ts: 0,
+                },
+                terms.seed,
+                access_mint_state,
+                split_state,
+            )],
+            &[creator],
+        )
+        .expect("create_listing");
+        
+        Product {
+            creator: creator.pubkey(),
+            listing: pda::listing(&creator.pubkey(), &terms.content_id, terms.seed).0,
+            access_mint_state,
+            access_mint: mint.pubkey(),
+            split_state,
+            payment: terms.payment,
+        }
+    }
+    
+    /// Purchase of `product` by `buyer`, before its escrow exists
+    pub fn purchase(&self, buyer: &Pubkey, product: &Product, escrow_seed: u64) -> Purchase {
+        let listing: Listing = self.account(&product.listing);
+        let access_mint_state: AccessMintState = self.account(&product.access_mint_state);
+        
+        Purchase::new(
+            *buyer,
+            escrow_seed,
+            product.listing,
+            &listing,
+            &access_mint_state,
+            product.payment.token_program(),
+        )
+    }
+    
+    /// Open `buyer`'s escrow for `product` with initialize_escrow and the vault's token account
+    pub fn open_escrow(&mut self, buyer: &Keypair, product: &Product, escrow_seed: u64) -> Purchase {
+        let purchase = self.purchase(&buyer.pubkey(), product, escrow_seed);
+        let mut transaction = vec![instructions::initialize_escrow(&purchase)];
+        // SPL escrows hold the payment in the vault's associated token account
+        transaction.extend(instructions::create_token_account(buyer.pubkey(), purchase.vault(), purchase.payment));
+        self.send(&transaction, &[buyer]).expect("initialize_escrow");
+        
+        purchase
+    }
+    
+    /// Withdraw everything credited to `recipient` and return what they received
+    pub fn claim(&mut self, recipient: &Keypair, product: &Product) -> u64 {
+        let before = self.balance(&recipient.pubkey(), product.payment);
+        self.send(
+            &[instructions::claim(recipient.pubkey(), product.split_state, product.payment)],
+            &[recipient],
+        )
+        .expect("claim");
+        self.balance(&recipient.pubkey(), product.payment) - before
+    }
+}
+
+impl Default for TestEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Terms of a product created with [`TestEnv::create_product`]
+#[derive(Clone)]
+pub struct ProductTerms {
+    pub content_id: [u8; 32],
+    pub seed: u64,
+    pub price: u64,
+    pub payment: PaymentMode,
+    pub platform_fee_bps: u16,
+    pub collaborators: Vec<CollaboratorShare>,
+    pub transfer_mode: TransferMode,
+    pub max_supply: Option<u64>,
+    pub refund_window_secs: i64,
+}
+
+impl ProductTerms {
+    /// Transferable, permanent and unlimited access with a 2.5% platform fee, no collaborators and no refund window
+    pub fn new(content_id: [u8; 32], price: u64, payment: PaymentMode) -> Self {
+        Self {
+            content_id,
+            seed: 1,
+            price,
+            payment,
+            platform_fee_bps: 250,
+            collaborators: Vec::new(),
+            transfer_mode: TransferMode::Transferable,
+            max_supply: None,
+            refund_window_secs: 0,
+        }
+    }
+}
+
+/// Accounts of a product created with [`TestEnv::create_product`]
+#[derive(Clone, Debug)]
+pub struct Product {
+    pub creator: Pubkey,
+    pub listing: Pubkey,
+    pub access_mint_state: Pubkey,
+    pub access_mint: Pubkey,
+    pub split_state: Pubkey,
+    pub payment: PaymentMode,
+}
+
+/// Assert that a transaction failed with the named custom error of `program_id`
+/// For errors raised inside a CPI, `program_id` is the program that raised it
+#[track_caller]
+pub fn assert_program_error(result: TransactionResult, program_id: &Pubkey, name: &str) {
+    let failed = match result {
+        Ok(_) => panic!("expected {name}, but the transaction succeeded"),
+        Err(failed) => failed,
+    };
+    
+    match failed.err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            let error = decode_error(program_id, code)
+                .unwrap_or_else(|| panic!("code {code} is not an error of {program_id}; logs: {:#?}", failed.meta.logs));
+            assert_eq!(error.name(), name, "logs: {:#?}", failed.meta.logs);
+        }
+        other => panic!("expected {name}, got {other:?}; logs: {:#?}", failed.meta.logs),
+    }
+}
+
+fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Program binary produced by `anchor build` in `workspace`
+fn deployed_program(workspace: &str, name: &str) -> PathBuf {
+    workspace_dir()
+        .join(workspace)
+        .join("target/deploy")
+        .join(format!("{name}.so"))
+}
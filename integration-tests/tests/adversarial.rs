@@ -0,0 +1,406 @@
+use ownmark_integration_tests::{assert_program_error, Product, ProductTerms, TestEnv};
+use ownmark_sdk::accounts::{AccessMintState, EscrowState, EscrowStatus, SplitState};
+use ownmark_sdk::distribution::state::{CollaboratorShare, DustPolicy};
+use ownmark_sdk::payment_escrow::state::ListingConfig;
+use ownmark_sdk::{distribution, instructions, payment_escrow, pda, PaymentMode, Purchase};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PRICE: u64 = LAMPORTS_PER_SOL;
+
+/// Two SOL products by different creators; the first has one collaborator with a 20% share
+struct Marketplace {
+    env: TestEnv,
+    creator: Keypair,
+    collaborator: Keypair,
+    product: Product,
+    other: Product,
+}
+
+impl Marketplace {
+    fn new() -> Self {
+        let mut env = TestEnv::new();
+        let creator = env.funded_keypair();
+        let other_creator = env.funded_keypair();
+        let collaborator = env.funded_keypair();
+        
+        let terms = ProductTerms {
+            collaborators: vec![CollaboratorShare {
+                pubkey: collaborator.pubkey(),
+                share_bps: 2_000,
+            }],
+            ..ProductTerms::new([1; 32], PRICE, PaymentMode::Sol)
+        };
+        let product = env.create_product(&creator, &terms);
+        let other = env.create_product(&other_creator, &ProductTerms::new([2; 32], PRICE, PaymentMode::Sol));
+        
+        Self {
+            env,
+            creator,
+            collaborator,
+            product,
+            other,
+        }
+    }
+    
+    /// Sell the first product once, crediting the collaborator's share
+    fn sell(&mut self) {
+        let buyer = self.env.funded_keypair();
+        let purchase = self.env.open_escrow(&buyer, &self.product, 1);
+        self.env
+            .send(&[instructions::buy_and_mint(&purchase, PRICE)], &[&buyer])
+            .expect("buy_and_mint");
+    }
+    
+    fn split(&self) -> SplitState {
+        self.env.account(&self.product.split_state)
+    }
+}
+
+#[test]
+fn buy_and_mint_rejects_a_split_from_another_listing() {
+    let mut market = Marketplace::new();
+    let buyer = market.env.funded_keypair();
+    let purchase = market.env.open_escrow(&buyer, &market.product, 1);
+    
+    // Route the payment to the other creator's split and vault
+    let swapped = Purchase {
+        split_state: market.other.split_state,
+        ..purchase.clone()
+    };
+    assert_program_error(
+        market.env.send(&[instructions::buy_and_mint(&swapped, PRICE)], &[&buyer]),
+        &payment_escrow::ID,
+        "InvalidContentId",
+    );
+    
+    // Nothing moved: the escrow is still open and the other vault only holds its rent reserve
+    let escrow: EscrowState = market.env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Initialized);
+    assert_eq!(market.env.balance(&purchase.vault(), PaymentMode::Sol), 0);
+    assert_eq!(
+        market.env.balance(&swapped.distribution_vault(), PaymentMode::Sol),
+        market.env.split_vault_reserve()
+    );
+    
+    // The same escrow still buys through its own split
+    market
+        .env
+        .send(&[instructions::buy_and_mint(&purchase, PRICE)], &[&buyer])
+        .expect("buy_and_mint");
+    assert_eq!(market.split().creator_unclaimed, 775_000_000);
+    assert_eq!(market.env.account::<SplitState>(&market.other.split_state).creator_unclaimed, 0);
+}
+
+/// Swap the access mint of `product` into `purchase`
+fn with_access_mint_of(env: &TestEnv, purchase: &Purchase, product: &Product) -> Purchase {
+    let foreign = env.purchase(&purchase.buyer, product, purchase.escrow_seed);
+    Purchase {
+        access_mint_state: foreign.access_mint_state,
+        access_mint: foreign.access_mint,
+        access_mint_authority: foreign.access_mint_authority,
+        ..purchase.clone()
+    }
+}
+
+#[test]
+fn buy_and_mint_rejects_an_access_mint_for_other_content() {
+    let mut market = Marketplace::new();
+    let buyer = market.env.funded_keypair();
+    let purchase = market.env.open_escrow(&buyer, &market.product, 1);
+    let sequel = market
+        .env
+        .create_product(&market.creator, &ProductTerms::new([5; 32], PRICE, PaymentMode::Sol));
+    
+    // The creator's own access mint for another piece of content
+    let swapped = with_access_mint_of(&market.env, &purchase, &sequel);
+    assert_program_error(
+        market.env.send(&[instructions::buy_and_mint(&swapped, PRICE)], &[&buyer]),
+        &payment_escrow::ID,
+        "InvalidContentId",
+    );
+    
+    let escrow: EscrowState = market.env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Initialized);
+    assert_eq!(market.env.account::<AccessMintState>(&sequel.access_mint_state).total_minted, 0);
+}
+
+#[test]
+fn buy_and_mint_rejects_a_split_for_other_content() {
+    let mut market = Marketplace::new();
+    let buyer = market.env.funded_keypair();
+    let purchase = market.env.open_escrow(&buyer, &market.product, 1);
+    let sequel = market
+        .env
+        .create_product(&market.creator, &ProductTerms::new([5; 32], PRICE, PaymentMode::Sol));
+    
+    // The creator's own split for another piece of content
+    let swapped = Purchase {
+        split_state: sequel.split_state,
+        ..purchase.clone()
+    };
+    assert_program_error(
+        market.env.send(&[instructions::buy_and_mint(&swapped, PRICE)], &[&buyer]),
+        &payment_escrow::ID,
+        "InvalidContentId",
+    );
+    
+    assert_eq!(
+        market.env.balance(&swapped.distribution_vault(), PaymentMode::Sol),
+        market.env.split_vault_reserve()
+    );
+}
+
+#[test]
+fn buy_and_mint_rejects_another_creators_product_for_the_same_content() {
+    let mut market = Marketplace::new();
+    let buyer = market.env.funded_keypair();
+    let purchase = market.env.open_escrow(&buyer, &market.product, 1);
+    let copier = market.env.funded_keypair();
+    let copy = market
+        .env
+        .create_product(&copier, &ProductTerms::new([1; 32], PRICE, PaymentMode::Sol));
+    
+    // Same content id, but the access mint, the split or both belong to another creator
+    let access_mint_swapped = with_access_mint_of(&market.env, &purchase, &copy);
+    let split_swapped = Purchase {
+        split_state: copy.split_state,
+        ..purchase.clone()
+    };
+    let both_swapped = Purchase {
+        split_state: copy.split_state,
+        ..access_mint_swapped.clone()
+    };
+    for swapped in [access_mint_swapped, split_swapped, both_swapped] {
+        assert_program_error(
+            market.env.send(&[instructions::buy_and_mint(&swapped, PRICE)], &[&buyer]),
+            &payment_escrow::ID,
+            "InvalidCreator",
+        );
+    }
+    
+    let escrow: EscrowState = market.env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Initialized);
+    assert_eq!(market.env.account::<SplitState>(&copy.split_state).creator_unclaimed, 0);
+}
+
+#[test]
+fn release_payout_rejects_a_split_from_another_listing() {
+    let mut market = Marketplace::new();
+    let creator = market.env.funded_keypair();
+    let buyer = market.env.funded_keypair();
+    let terms = ProductTerms {
+        refund_window_secs: 60,
+        ..ProductTerms::new([3; 32], PRICE, PaymentMode::Sol)
+    };
+    let product = market.env.create_product(&creator, &terms);
+    let purchase = market.env.open_escrow(&buyer, &product, 1);
+    market
+        .env
+        .send(&[instructions::buy_and_mint(&purchase, PRICE)], &[&buyer])
+        .expect("buy_and_mint");
+    market.env.warp(60);
+    
+    // The held payment can only be credited to the split it was paid into
+    let swapped = Purchase {
+        split_state: market.other.split_state,
+        ..purchase.clone()
+    };
+    assert_program_error(
+        market.env.send(&[instructions::release_payout(&swapped)], &[]),
+        &payment_escrow::ID,
+        "InvalidSplitState",
+    );
+    
+    market
+        .env
+        .send(&[instructions::release_payout(&purchase)], &[])
+        .expect("release_payout");
+}
+
+#[test]
+fn create_listing_rejects_another_creators_split() {
+    let mut market = Marketplace::new();
+    
+    let instruction = instructions::create_listing(
+        market.creator.pubkey(),
+        [1; 32],
+        PRICE,
+        PaymentMode::Sol,
+        ListingConfig {
+            requires_approval: false,
+            refund_window_secs: 0,
+            sale_start_ts: 0,
+            sale_end_ts: 0,
+        },
+        7,
+        market.product.access_mint_state,
+        market.other.split_state,
+    );
+    assert_program_error(
+        market.env.send(&[instruction], &[&market.creator]),
+        &payment_escrow::ID,
+        "InvalidSplitState",
+    );
+}
+
+#[test]
+fn distribute_only_accepts_the_split_distributor() {
+    let mut market = Marketplace::new();
+    let attacker = market.env.funded_keypair();
+    
+    // Fund the vault directly and try to credit it without going through the escrow program
+    market
+        .env
+        .svm
+        .airdrop(&pda::split_vault(&market.product.split_state).0, PRICE)
+        .expect("fund split vault");
+    assert_program_error(
+        market.env.send(
+            &[instructions::distribute(
+                attacker.pubkey(),
+                market.product.split_state,
+                PaymentMode::Sol,
+                PRICE,
+            )],
+            &[&attacker],
+        ),
+        &distribution::ID,
+        "Unauthorized",
+    );
+}
+
+#[test]
+fn update_split_requires_the_reduced_collaborator_account() {
+    let mut market = Marketplace::new();
+    let split = market.split();
+    let reduced = vec![CollaboratorShare {
+        pubkey: market.collaborator.pubkey(),
+        share_bps: 500,
+    }];
+    
+    // Drop the collaborator from the remaining accounts
+    let mut instruction = instructions::update_split(&split, reduced.clone(), 250, DustPolicy::Creator, false);
+    assert_eq!(instruction.accounts.len(), 7);
+    instruction.accounts.truncate(6);
+    assert_program_error(
+        market.env.send(&[instruction], &[&market.creator]),
+        &distribution::ID,
+        "MissingCollaboratorSignature",
+    );
+    
+    // Passing the collaborator without their signature is no better
+    let mut instruction = instructions::update_split(&split, reduced.clone(), 250, DustPolicy::Creator, false);
+    instruction.accounts[6].is_signer = false;
+    assert_program_error(
+        market.env.send(&[instruction], &[&market.creator]),
+        &distribution::ID,
+        "MissingCollaboratorSignature",
+    );
+    
+    let instruction = instructions::update_split(&split, reduced, 250, DustPolicy::Creator, false);
+    market
+        .env
+        .send(&[instruction], &[&market.creator, &market.collaborator])
+        .expect("update_split co-signed by the collaborator");
+    assert_eq!(market.split().collaborators[0].share_bps, 500);
+}
+
+#[test]
+fn collaborators_with_a_balance_cannot_be_removed_or_claimed_for() {
+    let mut market = Marketplace::new();
+    market.sell();
+    let stranger = market.env.funded_keypair();
+    
+    // Removing the collaborator would strand their credited balance, even with their signature
+    let instruction = instructions::update_split(&market.split(), Vec::new(), 250, DustPolicy::Creator, false);
+    assert_program_error(
+        market
+            .env
+            .send(&[instruction], &[&market.creator, &market.collaborator]),
+        &distribution::ID,
+        "UnclaimedBalance",
+    );
+    
+    // Nobody else can withdraw it
+    assert_program_error(
+        market.env.send(
+            &[instructions::claim(stranger.pubkey(), market.product.split_state, PaymentMode::Sol)],
+            &[&stranger],
+        ),
+        &distribution::ID,
+        "NothingToClaim",
+    );
+    
+    assert_eq!(market.env.claim(&market.collaborator, &market.product), 200_000_000);
+}
+
+#[test]
+fn cancel_escrow_refuses_a_substituted_token_vault() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    let payment = env.create_payment_mint(&mint_authority.pubkey());
+    env.mint_payment_tokens(payment, &mint_authority, &buyer.pubkey(), PRICE);
+    
+    let product = env.create_product(&creator, &ProductTerms::new([4; 32], PRICE, payment));
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    env.send(&[instructions::deposit(&purchase, PRICE)], &[&buyer])
+        .expect("deposit");
+    
+    // Cancelling against any account but the vault's token account would close the escrow unrefunded
+    let mut instruction = instructions::cancel_escrow(creator.pubkey(), &purchase);
+    instruction.accounts[6].pubkey = Pubkey::new_unique();
+    assert_program_error(
+        env.send(&[instruction], &[&creator]),
+        &payment_escrow::ID,
+        "InvalidVault",
+    );
+    assert_eq!(env.token_balance(&payment.token_account(&purchase.vault())), PRICE);
+    
+    env.send(&[instructions::cancel_escrow(creator.pubkey(), &purchase)], &[&creator])
+        .expect("cancel_escrow");
+    assert_eq!(env.balance(&buyer.pubkey(), payment), PRICE);
+    assert!(env.svm.get_account(&payment.token_account(&purchase.vault())).is_none());
+    assert!(env.svm.get_account(&purchase.escrow()).is_none());
+}
+
+#[test]
+fn close_split_sweeps_stray_funds_but_waits_for_held_payments() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let terms = ProductTerms {
+        refund_window_secs: 60,
+        ..ProductTerms::new([5; 32], PRICE, PaymentMode::Sol)
+    };
+    let product = env.create_product(&creator, &terms);
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    env.send(&[instructions::buy_and_mint(&purchase, PRICE)], &[&buyer])
+        .expect("buy_and_mint");
+    assert_eq!(env.account::<SplitState>(&product.split_state).held_amount, PRICE);
+    
+    // The held payment blocks the close; a lamport sent straight to the vault changes nothing
+    let vault = pda::split_vault(&product.split_state).0;
+    env.svm.airdrop(&vault, 1).expect("fund split vault");
+    let close = instructions::close_split(creator.pubkey(), product.split_state, PaymentMode::Sol);
+    assert_program_error(
+        env.send(std::slice::from_ref(&close), &[&creator]),
+        &distribution::ID,
+        "VaultNotEmpty",
+    );
+    
+    env.send(&[instructions::request_refund(&purchase)], &[&buyer])
+        .expect("request_refund");
+    assert_eq!(env.account::<SplitState>(&product.split_state).held_amount, 0);
+    
+    // With nothing owed or held, the stray lamport goes to the creator with the vault rent
+    let creator_before = env.balance(&creator.pubkey(), PaymentMode::Sol);
+    let vault_lamports = env.balance(&vault, PaymentMode::Sol);
+    assert_eq!(vault_lamports, env.split_vault_reserve() + 1);
+    env.send(&[close], &[&creator]).expect("close_split");
+    assert!(env.svm.get_account(&vault).is_none());
+    assert!(env.balance(&creator.pubkey(), PaymentMode::Sol) > creator_before + vault_lamports);
+}
@@ -0,0 +1,200 @@
+use ownmark_integration_tests::{assert_program_error, ProductTerms, TestEnv};
+use ownmark_sdk::access_mint::state::TransferMode;
+use ownmark_sdk::accounts::{AccessMintState, AccessRecord, EscrowState, EscrowStatus, SplitState};
+use ownmark_sdk::distribution::state::CollaboratorShare;
+use ownmark_sdk::{instructions, PaymentMode};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signature::Signer;
+
+#[test]
+fn sol_purchase_mints_access_and_credits_every_recipient() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let collaborator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let treasury = env.treasury.insecure_clone();
+    
+    let terms = ProductTerms {
+        collaborators: vec![CollaboratorShare {
+            pubkey: collaborator.pubkey(),
+            share_bps: 2_000,
+        }],
+        ..ProductTerms::new([1; 32], LAMPORTS_PER_SOL, PaymentMode::Sol)
+    };
+    let product = env.create_product(&creator, &terms);
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    
+    // buy_and_mint pays, mints access and distributes through CPIs in one instruction
+    env.send(&[instructions::buy_and_mint(&purchase, LAMPORTS_PER_SOL)], &[&buyer])
+        .expect("buy_and_mint");
+    
+    let escrow: EscrowState = env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.settled_amount, LAMPORTS_PER_SOL);
+    assert_eq!(env.token_balance(&purchase.buyer_access_token_account()), 1);
+    
+    let record: AccessRecord = env.account(&purchase.access_record());
+    assert_eq!(record.buyer, buyer.pubkey());
+    assert_eq!(record.escrow, purchase.escrow());
+    assert_eq!(record.edition, 1);
+    assert!(record.is_valid(0));
+    
+    // 2.5% platform fee, 20% collaborator, the rest to the creator
+    let split: SplitState = env.account(&product.split_state);
+    assert_eq!(split.platform_unclaimed, 25_000_000);
+    assert_eq!(split.collaborators[0].unclaimed, 200_000_000);
+    assert_eq!(split.creator_unclaimed, 775_000_000);
+    let reserve = env.split_vault_reserve();
+    assert_eq!(env.balance(&purchase.distribution_vault(), PaymentMode::Sol), reserve + LAMPORTS_PER_SOL);
+    assert_eq!(env.balance(&purchase.vault(), PaymentMode::Sol), 0);
+    
+    assert_eq!(env.claim(&creator, &product), 775_000_000);
+    assert_eq!(env.claim(&collaborator, &product), 200_000_000);
+    assert_eq!(env.claim(&treasury, &product), 25_000_000);
+    assert_eq!(env.balance(&purchase.distribution_vault(), PaymentMode::Sol), reserve);
+}
+
+#[test]
+fn spl_purchase_is_held_through_the_refund_window_then_distributed() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let collaborator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    let treasury = env.treasury.insecure_clone();
+    
+    let payment = env.create_payment_mint(&mint_authority.pubkey());
+    let price = 5_000_000;
+    env.mint_payment_tokens(payment, &mint_authority, &buyer.pubkey(), price);
+    
+    let terms = ProductTerms {
+        collaborators: vec![CollaboratorShare {
+            pubkey: collaborator.pubkey(),
+            share_bps: 1_000,
+        }],
+        transfer_mode: TransferMode::RecordOnly,
+        refund_window_secs: 3_600,
+        ..ProductTerms::new([2; 32], price, payment)
+    };
+    let product = env.create_product(&creator, &terms);
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    
+    env.send(&[instructions::buy_and_mint(&purchase, price)], &[&buyer])
+        .expect("buy_and_mint");
+    
+    // Access is granted at once, but the payment waits in the split vault uncredited
+    let escrow: EscrowState = env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Settled);
+    assert_eq!(env.balance(&buyer.pubkey(), payment), 0);
+    assert_eq!(env.token_balance(&payment.token_account(&purchase.distribution_vault())), price);
+    assert!(env.account::<AccessRecord>(&purchase.access_record()).is_valid(0));
+    assert!(env.svm.get_account(&purchase.buyer_access_token_account()).is_none());
+    
+    let split: SplitState = env.account(&product.split_state);
+    assert_eq!(split.creator_unclaimed + split.platform_unclaimed + split.collaborators[0].unclaimed, 0);
+    assert_eq!(split.held_amount, price);
+    
+    assert_program_error(
+        env.send(&[instructions::release_payout(&purchase)], &[]),
+        &ownmark_sdk::payment_escrow::ID,
+        "RefundWindowOpen",
+    );
+    
+    // release_payout distributes through the distribution program once the window closes
+    env.warp(3_600);
+    env.send(&[instructions::release_payout(&purchase)], &[])
+        .expect("release_payout");
+    
+    let escrow: EscrowState = env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Completed);
+    
+    let split: SplitState = env.account(&product.split_state);
+    assert_eq!(split.held_amount, 0);
+    assert_eq!(split.platform_unclaimed, 125_000);
+    assert_eq!(split.collaborators[0].unclaimed, 500_000);
+    assert_eq!(split.creator_unclaimed, 4_375_000);
+    
+    for recipient in [&creator, &collaborator, &treasury] {
+        env.create_token_account(&recipient.pubkey(), payment);
+    }
+    assert_eq!(env.claim(&creator, &product), 4_375_000);
+    assert_eq!(env.claim(&collaborator, &product), 500_000);
+    assert_eq!(env.claim(&treasury, &product), 125_000);
+    assert_eq!(env.token_balance(&payment.token_account(&purchase.distribution_vault())), 0);
+    
+    // Closing the completed escrow also closes its emptied token vault
+    env.send(&[instructions::close_completed_escrow(&purchase)], &[])
+        .expect("close_completed_escrow");
+    assert!(env.svm.get_account(&purchase.escrow()).is_none());
+    assert!(env.svm.get_account(&payment.token_account(&purchase.vault())).is_none());
+}
+
+#[test]
+fn refunded_edition_can_be_sold_again() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let first_buyer = env.funded_keypair();
+    let second_buyer = env.funded_keypair();
+    
+    let terms = ProductTerms {
+        max_supply: Some(1),
+        refund_window_secs: 3_600,
+        ..ProductTerms::new([9; 32], LAMPORTS_PER_SOL, PaymentMode::Sol)
+    };
+    let product = env.create_product(&creator, &terms);
+    
+    let first = env.open_escrow(&first_buyer, &product, 1);
+    env.send(&[instructions::buy_and_mint(&first, terms.price)], &[&first_buyer])
+        .expect("first buy_and_mint");
+    
+    // The only edition is taken, so the drop refuses payment
+    let second = env.open_escrow(&second_buyer, &product, 1);
+    assert_program_error(
+        env.send(&[instructions::buy_and_mint(&second, terms.price)], &[&second_buyer]),
+        &ownmark_sdk::payment_escrow::ID,
+        "SoldOut",
+    );
+    
+    // Refunding revokes the first purchase and gives its edition back
+    env.send(&[instructions::request_refund(&first)], &[&first_buyer])
+        .expect("request_refund");
+    
+    let state: AccessMintState = env.account(&product.access_mint_state);
+    assert_eq!(state.total_minted, 1);
+    assert_eq!(state.active_supply, 0);
+    
+    env.send(&[instructions::buy_and_mint(&second, terms.price)], &[&second_buyer])
+        .expect("second buy_and_mint");
+    
+    // Edition numbers are never reused
+    assert_eq!(env.account::<AccessRecord>(&second.access_record()).edition, 2);
+    let state: AccessMintState = env.account(&product.access_mint_state);
+    assert_eq!(state.total_minted, 2);
+    assert_eq!(state.active_supply, 1);
+}
+
+#[test]
+fn expired_spl_escrow_returns_both_rents_to_the_buyer() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    
+    let payment = env.create_payment_mint(&mint_authority.pubkey());
+    let product = env.create_product(&creator, &ProductTerms::new([10; 32], 5_000_000, payment));
+    
+    // The buyer pays the rent of the escrow and of its token vault, then abandons the checkout
+    let balance_before = env.svm.get_balance(&buyer.pubkey()).unwrap();
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    let vault_token_account = payment.token_account(&purchase.vault());
+    assert!(env.svm.get_account(&vault_token_account).is_some());
+    assert!(env.svm.get_balance(&buyer.pubkey()).unwrap() < balance_before);
+    
+    env.warp(EscrowState::CHECKOUT_TTL_SECS);
+    env.send(&[instructions::close_expired_escrow(&purchase)], &[])
+        .expect("close_expired_escrow");
+    
+    assert!(env.svm.get_account(&purchase.escrow()).is_none());
+    assert!(env.svm.get_account(&vault_token_account).is_none());
+    assert_eq!(env.svm.get_balance(&buyer.pubkey()).unwrap(), balance_before);
+}
@@ -0,0 +1,143 @@
+use ownmark_integration_tests::{assert_program_error, ProductTerms, TestEnv};
+use ownmark_sdk::access_mint::state::TransferMode;
+use ownmark_sdk::accounts::{AccessRecord, EscrowState, EscrowStatus, SplitState};
+use ownmark_sdk::distribution::state::{CollaboratorShare, DustPolicy};
+use ownmark_sdk::{instructions, pda};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+/// Transfer fee of the Token-2022 payment mints, withheld from every transfer
+const FEE_BPS: u16 = 100;
+
+/// Fee withheld from a transfer of `amount`, rounded up like Token-2022 does
+fn fee(amount: u64) -> u64 {
+    (amount * u64::from(FEE_BPS)).div_ceil(10_000)
+}
+
+#[test]
+fn transfer_fee_purchase_credits_what_the_split_vault_received() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let collaborator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    let treasury = env.treasury.insecure_clone();
+    
+    let payment = env.create_transfer_fee_mint(&mint_authority.pubkey(), FEE_BPS);
+    let price = 10_000_000;
+    env.mint_payment_tokens(payment, &mint_authority, &buyer.pubkey(), price);
+    
+    let terms = ProductTerms {
+        collaborators: vec![CollaboratorShare {
+            pubkey: collaborator.pubkey(),
+            share_bps: 1_000,
+        }],
+        transfer_mode: TransferMode::RecordOnly,
+        ..ProductTerms::new([6; 32], price, payment)
+    };
+    let product = env.create_product(&creator, &terms);
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    
+    env.send(&[instructions::buy_and_mint(&purchase, price)], &[&buyer])
+        .expect("buy_and_mint");
+    
+    // The fee is withheld twice: on the deposit into the escrow vault and on the move to the split vault
+    let deposited = price - fee(price);
+    let settled = deposited - fee(deposited);
+    let split_vault_token_account = payment.token_account(&purchase.distribution_vault());
+    assert_eq!(env.balance(&buyer.pubkey(), payment), 0);
+    assert_eq!(env.token_balance(&split_vault_token_account), settled);
+    assert!(env.account::<AccessRecord>(&purchase.access_record()).is_valid(0));
+    
+    let escrow: EscrowState = env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, price);
+    assert_eq!(escrow.settled_amount, settled);
+    
+    // The split credits exactly what reached its vault, not the price the buyer paid
+    let split: SplitState = env.account(&product.split_state);
+    let credited = [split.creator_unclaimed, split.collaborators[0].unclaimed, split.platform_unclaimed];
+    assert_eq!(credited.iter().sum::<u64>(), settled);
+    
+    // Each claim withholds the fee again from what the recipient receives
+    for recipient in [&creator, &collaborator, &treasury] {
+        env.create_token_account(&recipient.pubkey(), payment);
+    }
+    assert_eq!(env.claim(&creator, &product), credited[0] - fee(credited[0]));
+    assert_eq!(env.claim(&collaborator, &product), credited[1] - fee(credited[1]));
+    assert_eq!(env.claim(&treasury, &product), credited[2] - fee(credited[2]));
+    assert_eq!(env.token_balance(&split_vault_token_account), 0);
+    
+    // The escrow's token vault still holds the deposit fee, which is harvested before it is closed
+    let escrow_vault_token_account = payment.token_account(&purchase.vault());
+    assert_eq!(env.token_balance(&escrow_vault_token_account), 0);
+    assert_eq!(env.withheld_fees(&escrow_vault_token_account), fee(price));
+    env.send(&[instructions::close_completed_escrow(&purchase)], &[])
+        .expect("close_completed_escrow");
+    assert!(env.svm.get_account(&escrow_vault_token_account).is_none());
+}
+
+#[test]
+fn transfer_fee_deposit_is_refunded_net_of_fees_on_cancel() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let buyer = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    
+    let payment = env.create_transfer_fee_mint(&mint_authority.pubkey(), FEE_BPS);
+    let price = 10_000_000;
+    env.mint_payment_tokens(payment, &mint_authority, &buyer.pubkey(), price);
+    
+    let product = env.create_product(&creator, &ProductTerms::new([7; 32], price, payment));
+    let purchase = env.open_escrow(&buyer, &product, 1);
+    
+    env.send(&[instructions::deposit(&purchase, price)], &[&buyer])
+        .expect("deposit");
+    
+    let vault_token_account = payment.token_account(&purchase.vault());
+    let deposited = price - fee(price);
+    assert_eq!(env.token_balance(&vault_token_account), deposited);
+    
+    // The whole vault balance goes back, less the fee withheld from the refund transfer,
+    // and the vault is closed even though it held withheld fees
+    env.send(&[instructions::cancel_escrow(buyer.pubkey(), &purchase)], &[&buyer])
+        .expect("cancel_escrow");
+    
+    assert_eq!(env.balance(&buyer.pubkey(), payment), deposited - fee(deposited));
+    assert!(env.svm.get_account(&vault_token_account).is_none());
+    
+    let escrow: EscrowState = env.account(&purchase.escrow());
+    assert!(escrow.status == EscrowStatus::Cancelled);
+    
+    // Nothing reached the split
+    let split: SplitState = env.account(&product.split_state);
+    assert_eq!(split.creator_unclaimed + split.platform_unclaimed + split.held_amount, 0);
+    assert_eq!(env.token_balance(&payment.token_account(&purchase.distribution_vault())), 0);
+}
+
+#[test]
+fn transfer_hook_mint_is_rejected() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_keypair();
+    let mint_authority = env.funded_keypair();
+    
+    // A hook program could fail or change every payout, so no split accepts such a mint
+    let payment = env.create_transfer_hook_mint(&mint_authority.pubkey(), Pubkey::new_unique());
+    assert_program_error(
+        env.send(
+            &[instructions::initialize_split(
+                creator.pubkey(),
+                [8; 32],
+                250,
+                Vec::new(),
+                1,
+                pda::escrow_authority().0,
+                DustPolicy::Creator,
+                payment.mint(),
+            )],
+            &[&creator],
+        ),
+        &ownmark_sdk::distribution::ID,
+        "UnsupportedMintExtension",
+    );
+}
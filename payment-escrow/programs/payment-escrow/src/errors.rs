@@ -40,4 +40,64 @@ pub enum EscrowError {
     
     #[msg("Insufficient funds in vault")]
     InsufficientFunds,
+    
+    #[msg("Listing is not active")]
+    ListingInactive,
+    
+    #[msg("Listing does not match escrow")]
+    InvalidListing,
+    
+    #[msg("Listing price does not match escrow price")]
+    PriceMismatch,
+    
+    #[msg("Payment token mint does not match listing")]
+    InvalidPaymentMint,
+    
+    #[msg("Access mint state does not match listing")]
+    InvalidAccessMintState,
+    
+    #[msg("Split state does not match listing")]
+    InvalidSplitState,
+    
+    #[msg("Listing requires creator approval - use deposit and settle")]
+    ApprovalRequired,
+    
+    #[msg("Invalid refund window")]
+    InvalidRefundWindow,
+    
+    #[msg("Refund window is still open")]
+    RefundWindowOpen,
+    
+    #[msg("Refund window has closed")]
+    RefundWindowClosed,
+    
+    #[msg("Token program must be SPL Token or Token-2022")]
+    InvalidTokenProgram,
+    
+    #[msg("Payment mint uses an unsupported extension (transfer hook)")]
+    UnsupportedMintExtension,
+    
+    #[msg("Access mint is sold out")]
+    SoldOut,
+    
+    #[msg("Sale window is invalid - end must be after start")]
+    InvalidSaleWindow,
+    
+    #[msg("Sale has not started yet")]
+    SaleNotStarted,
+    
+    #[msg("Sale has ended")]
+    SaleEnded,
+    
+    #[msg("Escrow checkout window has expired")]
+    EscrowExpired,
+    
+    #[msg("Escrow checkout window has not expired yet")]
+    EscrowNotExpired,
+    
+    #[msg("Platform is paused")]
+    PlatformPaused,
+    
+    #[msg("Listing is paused by the platform admin")]
+    ListingPaused,
 }
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use crate::state::{EscrowStatus, ListingConfig};
+
+/// Emitted when a creator creates a listing
+#[event]
+pub struct ListingCreated {
+    /// Listing PDA
+    pub listing: Pubkey,
+    
+    /// Creator who owns the listing
+    pub creator: Pubkey,
+    
+    /// Content being sold
+    pub content_id: [u8; 32],
+    
+    /// Seed used for PDA derivation
+    pub seed: u64,
+    
+    /// Price per purchase
+    pub price: u64,
+    
+    /// Payment token mint (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Access mint state buyers receive access from
+    pub access_mint_state: Pubkey,
+    
+    /// Split that receives the payments
+    pub split_state: Pubkey,
+    
+    /// Approval mode, refund window and sale window
+    pub config: ListingConfig,
+    
+    /// Time the listing was created
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator changes a listing's price or purchase terms
+#[event]
+pub struct ListingUpdated {
+    /// Listing PDA
+    pub listing: Pubkey,
+    
+    /// New price per purchase
+    pub price: u64,
+    
+    /// New approval mode, refund window and sale window
+    pub config: ListingConfig,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when a sale is paused, resumed or ended by the creator, or paused by the platform admin
+#[event]
+pub struct ListingStatusChanged {
+    /// Listing PDA
+    pub listing: Pubkey,
+    
+    /// Whether the creator accepts purchases
+    pub is_active: bool,
+    
+    /// Whether the platform admin has paused the listing
+    pub paused_by_admin: bool,
+    
+    /// End of the sale window (0 = open-ended)
+    pub sale_end_ts: i64,
+    
+    /// Time of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator closes a listing
+#[event]
+pub struct ListingClosed {
+    /// Listing PDA
+    pub listing: Pubkey,
+    
+    /// Creator receiving the rent
+    pub creator: Pubkey,
+    
+    /// Time of the close
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer opens an escrow against a listing
+#[event]
+pub struct EscrowInitialized {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer who opened the escrow
+    pub buyer: Pubkey,
+    
+    /// Creator being paid
+    pub creator: Pubkey,
+    
+    /// Listing the escrow was opened against
+    pub listing: Pubkey,
+    
+    /// Split that receives the payment
+    pub split_state: Pubkey,
+    
+    /// Content being purchased
+    pub content_id: [u8; 32],
+    
+    /// Price taken from the listing
+    pub price: u64,
+    
+    /// Payment token mint (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Seed used for PDA derivation
+    pub seed: u64,
+    
+    /// When the escrow can no longer be funded
+    pub expires_at: i64,
+    
+    /// Time the escrow was opened
+    pub timestamp: i64,
+}
+
+/// Emitted when the buyer's payment reaches the escrow vault
+#[event]
+pub struct PaymentReceived {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer who paid
+    pub buyer: Pubkey,
+    
+    /// Payment token mint (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount paid (before any transfer fee)
+    pub amount: u64,
+    
+    /// Whether the payment renews existing access
+    pub renewal: bool,
+    
+    /// Time of the payment
+    pub timestamp: i64,
+}
+
+/// Emitted when an escrow is cancelled and any payment returned to the buyer
+#[event]
+pub struct EscrowCancelled {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer receiving the refund
+    pub buyer: Pubkey,
+    
+    /// Buyer or creator who cancelled
+    pub cancelled_by: Pubkey,
+    
+    /// Payment token mint (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount returned to the buyer (lamports or tokens)
+    pub refunded_amount: u64,
+    
+    /// Time of the cancellation
+    pub timestamp: i64,
+}
+
+/// Emitted when access is granted and the payment moves to the distribution vault
+#[event]
+pub struct EscrowSettled {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer receiving access
+    pub buyer: Pubkey,
+    
+    /// Split that received the payment
+    pub split_state: Pubkey,
+    
+    /// Access token mint of the purchase
+    pub access_mint: Pubkey,
+    
+    /// Amount that reached the distribution vault
+    pub settled_amount: u64,
+    
+    /// End of the refund window the payment is held for (0 = credited at once)
+    pub held_until: i64,
+    
+    /// Whether the purchase renewed existing access
+    pub renewal: bool,
+    
+    /// Time of the settlement
+    pub timestamp: i64,
+}
+
+/// Emitted when a held payment is credited to the split after its refund window
+#[event]
+pub struct PayoutReleased {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Split the payment was credited to
+    pub split_state: Pubkey,
+    
+    /// Amount credited
+    pub amount: u64,
+    
+    /// Time of the release
+    pub timestamp: i64,
+}
+
+/// Emitted when a settled purchase is refunded inside its refund window
+#[event]
+pub struct EscrowRefunded {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer receiving the refund
+    pub buyer: Pubkey,
+    
+    /// Split the payment was returned from
+    pub split_state: Pubkey,
+    
+    /// Payment token mint (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount returned to the buyer (lamports or tokens)
+    pub refunded_amount: u64,
+    
+    /// Time of the refund
+    pub timestamp: i64,
+}
+
+/// Emitted when an expired or completed escrow is closed and its rent returned
+#[event]
+pub struct EscrowClosed {
+    /// Escrow state PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer receiving the rent
+    pub buyer: Pubkey,
+    
+    /// Status of the escrow when it was closed
+    pub status: EscrowStatus,
+    
+    /// Time of the close
+    pub timestamp: i64,
+}
@@ -1,27 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer, System};
-use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer};
+use anchor_lang::system_program::System;
+use anchor_spl::token::{Mint, Token};
 use anchor_spl::associated_token::AssociatedToken;
-use access_mint::{
-    program::AccessMint,
-    cpi::accounts::MintAccess as AccessMintAccounts,
-    cpi::mint_access,
-};
-use distribution::{
-    program::Distribution,
-    cpi::accounts::Distribute as DistributeAccounts,
-    cpi::distribute,
-};
+use access_mint::{program::AccessMint, state::AccessMintState};
+use distribution::{program::Distribution, state::{PlatformConfig, SplitState}};
+use crate::instructions::{PaymentTransfer, Settlement};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
-/// Main atomic instruction - handles payment to escrow vault
-/// In a complete implementation, this would also CPI to Access Mint and Revenue Split programs
-pub fn buy_and_mint<'info>(
-    ctx: Context<'_, '_, '_, 'info, BuyAndMint<'info>>,
-    payment_amount: u64,
-) -> Result<()> {
-    let escrow = &mut ctx.accounts.escrow_state;
+/// Main atomic instruction - deposits the payment and settles the escrow in one step
+/// Equivalent to `deposit` followed by `settle`; not available for approval-based listings
+pub fn buy_and_mint(ctx: Context<BuyAndMint>, payment_amount: u64) -> Result<()> {
+    purchase(ctx, payment_amount, false)?;
+    
+    msg!("Buy and mint completed successfully");
+    
+    Ok(())
+}
+
+/// Deposit the payment and settle in one step, minting new access or renewing existing access
+/// Shared by `buy_and_mint` and `renew`
+pub(crate) fn purchase(ctx: Context<BuyAndMint>, payment_amount: u64, renewal: bool) -> Result<()> {
+    // Emergency stop: no purchases while the platform is paused
+    require!(
+        !ctx.accounts.platform_config.paused,
+        EscrowError::PlatformPaused
+    );
+    
+    let escrow = &ctx.accounts.escrow_state;
     
     // Validate escrow status
     require!(
@@ -29,6 +36,13 @@ pub fn buy_and_mint<'info>(
         EscrowError::InvalidEscrowStatus
     );
     
+    // Validate the checkout window has not passed
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !escrow.is_expired(now),
+        EscrowError::EscrowExpired
+    );
+    
     // Validate payment amount matches price
     require!(
         payment_amount == escrow.price,
@@ -41,160 +55,96 @@ pub fn buy_and_mint<'info>(
         EscrowError::InvalidBuyer
     );
     
-    // Transfer payment to vault
-    if escrow.payment_token_mint.is_none() {
-        // SOL payment
-        transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.buyer.to_account_info(),
-                    to: ctx.accounts.vault.to_account_info(),
-                },
-            ),
-            payment_amount,
-        )?;
-    } else {
-        // SPL token payment
-        // Validate that token accounts are provided
-        require!(
-            ctx.accounts.buyer_token_account.key() != System::id(),
-            EscrowError::InvalidVault
-        );
-        require!(
-            ctx.accounts.vault_token_account.key() != System::id(),
-            EscrowError::InvalidVault
-        );
-        require!(
-            ctx.accounts.token_program.key() == anchor_spl::token::ID,
-            EscrowError::InvalidVault
-        );
-        
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                SplTransfer {
-                    from: ctx.accounts.buyer_token_account.to_account_info(),
-                    to: ctx.accounts.vault_token_account.to_account_info(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                },
-            ),
-            payment_amount,
-        )?;
-    }
+    // Validate sale terms against the creator's listing
+    escrow.validate_listing(ctx.accounts.listing.key(), &ctx.accounts.listing)?;
+    require!(
+        !ctx.accounts.listing.requires_approval,
+        EscrowError::ApprovalRequired
+    );
     
-    // Update escrow state
-    escrow.payment_amount = payment_amount;
+    let payment = PaymentTransfer {
+        buyer: ctx.accounts.buyer.to_account_info(),
+        vault: ctx.accounts.vault.to_account_info(),
+        payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+        buyer_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+        vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    
+    let settlement = Settlement {
+        escrow_state: &mut ctx.accounts.escrow_state,
+        access_mint_state: &ctx.accounts.access_mint_state,
+        split_state: &ctx.accounts.split_state,
+        payer: ctx.accounts.buyer.to_account_info(),
+        buyer: ctx.accounts.buyer.to_account_info(),
+        vault: ctx.accounts.vault.to_account_info(),
+        vault_bump: ctx.bumps.vault,
+        vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+        payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        escrow_authority: ctx.accounts.escrow_authority.to_account_info(),
+        escrow_authority_bump: ctx.bumps.escrow_authority,
+        access_mint_program: ctx.accounts.access_mint_program.to_account_info(),
+        access_mint: ctx.accounts.access_mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        buyer_access_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+        access_record: ctx.accounts.access_record.to_account_info(),
+        access_mint_event_authority: ctx.accounts.access_mint_event_authority.to_account_info(),
+        access_token_program: ctx.accounts.access_token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        distribution_program: ctx.accounts.distribution_program.to_account_info(),
+        platform_config: ctx.accounts.platform_config.to_account_info(),
+        distribution_vault: ctx.accounts.distribution_vault.to_account_info(),
+        distribution_vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+        distribution_event_authority: ctx.accounts.distribution_event_authority.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        renewal,
+    };
+    
+    // Validate the whole purchase before any money moves
+    settlement.validate(&ctx.accounts.listing)?;
+    
+    // Transfer payment to vault
+    payment.pay(settlement.escrow_state.payment_token_mint, payment_amount)?;
+    settlement.escrow_state.payment_amount = payment_amount;
+    settlement.escrow_state.status = EscrowStatus::Funded;
     
     msg!("Payment of {} received from buyer: {}", payment_amount, ctx.accounts.buyer.key());
     
-    // CPI to Access Mint program to mint access token to buyer
-    mint_access(
-        CpiContext::new(
-            ctx.accounts.access_mint_program.to_account_info(),
-            AccessMintAccounts {
-                buyer: ctx.accounts.buyer.to_account_info(),
-                payer: ctx.accounts.buyer.to_account_info(),
-                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
-                mint: ctx.accounts.access_mint.to_account_info(),
-                mint_authority: ctx.accounts.mint_authority.to_account_info(),
-                buyer_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
-                token_program: ctx.accounts.access_token_program.to_account_info(),
-                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-            },
-        ),
-    )?;
-    
-    // Store the access mint address in escrow
-    escrow.access_mint_address = Some(ctx.accounts.access_mint.key());
-    escrow.status = EscrowStatus::Completed;
-    
-    msg!("Access token minted to buyer: {}", ctx.accounts.buyer.key());
-    
-    // Transfer funds from escrow vault to distribution vault before distributing
-    if escrow.payment_token_mint.is_none() {
-        // SOL payment: Transfer from escrow vault to distribution vault
-        let escrow_key = escrow.key();
-        let vault_bump = ctx.bumps.vault;
-        let vault_seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[vault_bump],
-        ];
-        let signer_seeds = &[&vault_seeds[..]];
-        
-        // Use system program transfer to properly handle account creation and rent
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.distribution_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            payment_amount,
-        )?;
-        
-        msg!("Transferred {} lamports from escrow vault to distribution vault", payment_amount);
-    } else {
-        // SPL token payment: Transfer from escrow vault token account to distribution vault token account
-        let escrow_key = escrow.key();
-        let vault_bump = ctx.bumps.vault;
-        let vault_seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[vault_bump],
-        ];
-        let signer_seeds = &[&vault_seeds[..]];
-        
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                SplTransfer {
-                    from: ctx.accounts.vault_token_account.to_account_info(),
-                    to: ctx.accounts.distribution_vault_token_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            payment_amount,
-        )?;
-        
-        msg!("Transferred {} tokens from escrow vault to distribution vault", payment_amount);
+    emit_cpi!(PaymentReceived {
+        escrow: settlement.escrow_state.key(),
+        buyer: settlement.escrow_state.buyer,
+        payment_token_mint: settlement.escrow_state.payment_token_mint,
+        amount: payment_amount,
+        renewal,
+        timestamp: now,
+    });
+    
+    // Renewals only extend an existing access period, so they are final and paid out immediately
+    if renewal {
+        settlement.escrow_state.refund_window_secs = 0;
     }
     
-    // CPI to Distribution program to distribute funds from distribution vault
-    let remaining_accounts = ctx.remaining_accounts.to_vec();
-    
-    distribute(
-        CpiContext::new(
-            ctx.accounts.distribution_program.to_account_info(),
-            DistributeAccounts {
-                split_state: ctx.accounts.split_state.to_account_info(),
-                vault: ctx.accounts.distribution_vault.to_account_info(),
-                creator: ctx.accounts.creator.to_account_info(),
-                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
-                payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
-                vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
-                creator_token_account: ctx.accounts.creator_token_account.to_account_info(),
-                platform_treasury_token_account: ctx.accounts.platform_treasury_token_account.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-            },
-        )
-        .with_remaining_accounts(remaining_accounts),
-        payment_amount,
-    )?;
-    
-    msg!("Funds distributed to creator, platform, and collaborators");
-    
-    msg!("Buy and mint completed successfully");
+    // Mint (or renew) access and distribute the escrowed funds
+    settlement.execute()?;
+    
+    let escrow = &ctx.accounts.escrow_state;
+    emit_cpi!(EscrowSettled {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        split_state: escrow.split_state,
+        access_mint: ctx.accounts.access_mint.key(),
+        settled_amount: escrow.settled_amount,
+        held_until: if escrow.status == EscrowStatus::Settled { escrow.settle_after } else { 0 },
+        renewal,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BuyAndMint<'info> {
     /// The buyer making the payment
@@ -214,6 +164,27 @@ pub struct BuyAndMint<'info> {
     )]
     pub escrow_state: Account<'info, EscrowState>,
     
+    /// Listing the escrow was opened against
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+        constraint = listing.key() == escrow_state.listing @ EscrowError::InvalidListing,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config (distribution program) holding the global pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
     /// Vault PDA to hold SOL payments
     /// CHECK: Vault is a PDA derived from escrow state
     #[account(
@@ -223,6 +194,10 @@ pub struct BuyAndMint<'info> {
     )]
     pub vault: UncheckedAccount<'info>,
     
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the escrow payment mint; validated when SPL payment is used
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
     /// Buyer's SPL token account (for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
     #[account(mut)]
@@ -233,7 +208,7 @@ pub struct BuyAndMint<'info> {
     #[account(mut)]
     pub vault_token_account: UncheckedAccount<'info>,
     
-    /// Token program (for SPL payments)
+    /// Token program, SPL Token or Token-2022 (for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
     pub token_program: UncheckedAccount<'info>,
     
@@ -242,10 +217,17 @@ pub struct BuyAndMint<'info> {
     /// Access mint program
     pub access_mint_program: Program<'info, AccessMint>,
     
-    /// Access mint state PDA
-    /// CHECK: Validated by access mint program via CPI
+    /// Escrow authority PDA (authorized minter and distributor)
+    /// CHECK: PDA derived from the program-wide authority seed
+    #[account(
+        seeds = [EscrowState::AUTHORITY_SEED],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA (checked against the escrow before any transfer)
     #[account(mut)]
-    pub access_mint_state: UncheckedAccount<'info>,
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
     
     /// Access token mint
     #[account(mut)]
@@ -255,11 +237,20 @@ pub struct BuyAndMint<'info> {
     /// CHECK: Validated by access mint program via CPI
     pub mint_authority: UncheckedAccount<'info>,
     
-    /// Buyer's access token account (will be created if needed)
+    /// Buyer's access token account (will be created if needed; unused for record-only access)
     /// CHECK: Validated and potentially created by access mint program via CPI
     #[account(mut)]
     pub buyer_access_token_account: UncheckedAccount<'info>,
     
+    /// Buyer's access record PDA (created or extended by access mint program)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub access_record: UncheckedAccount<'info>,
+    
+    /// Access mint program's event authority PDA
+    /// CHECK: Validated by access mint program via CPI
+    pub access_mint_event_authority: UncheckedAccount<'info>,
+    
     /// Token program for access mint
     pub access_token_program: Program<'info, Token>,
     
@@ -271,10 +262,9 @@ pub struct BuyAndMint<'info> {
     /// Distribution program
     pub distribution_program: Program<'info, Distribution>,
     
-    /// Split state PDA (revenue split configuration)
-    /// CHECK: Validated by distribution program via CPI
+    /// Split state PDA (checked against the escrow before any transfer)
     #[account(mut)]
-    pub split_state: UncheckedAccount<'info>,
+    pub split_state: Box<Account<'info, SplitState>>,
     
     /// Distribution vault PDA (derived from split_state)
     /// CHECK: Vault is a PDA derived from split_state in the distribution program
@@ -287,32 +277,10 @@ pub struct BuyAndMint<'info> {
     #[account(mut)]
     pub distribution_vault_token_account: UncheckedAccount<'info>,
     
-    /// Creator account (receives their share)
+    /// Distribution program's event authority PDA
     /// CHECK: Validated by distribution program via CPI
-    #[account(mut)]
-    pub creator: UncheckedAccount<'info>,
-    
-    /// Platform treasury (receives platform fees)
-    /// CHECK: Validated by distribution program via CPI
-    #[account(mut)]
-    pub platform_treasury: UncheckedAccount<'info>,
-    
-    /// Payment token mint (System::id() for SOL, token mint for SPL)
-    /// CHECK: Used to determine payment type in distribution
-    pub payment_token_mint: UncheckedAccount<'info>,
-    
-    /// Creator's token account (for SPL payments)
-    /// CHECK: Optional, validated by distribution program when SPL payment is used
-    #[account(mut)]
-    pub creator_token_account: UncheckedAccount<'info>,
-    
-    /// Platform treasury token account (for SPL payments)
-    /// CHECK: Optional, validated by distribution program when SPL payment is used
-    #[account(mut)]
-    pub platform_treasury_token_account: UncheckedAccount<'info>,
+    pub distribution_event_authority: UncheckedAccount<'info>,
     
     /// System program
     pub system_program: Program<'info, System>,
-    
-    // Remaining accounts: Collaborator accounts (SOL) or token accounts (SPL)
 }
@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::System;
-use anchor_spl::token::{self, Transfer as SplTransfer};
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token_interface::{self, CloseAccount};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
 
 /// Cancel an escrow and refund the buyer if payment was made
+/// The buyer can cancel before settlement; the creator can refund (reject) a funded escrow
+/// Empties and closes the SOL vault and token vault so their rent returns to the buyer
 pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
     let escrow = &mut ctx.accounts.escrow_state;
     
@@ -19,75 +23,168 @@ pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
         EscrowError::EscrowAlreadyCancelled
     );
     
-    // Validate buyer is the one cancelling
+    // Settled escrows have already moved funds to the distribution vault; use request_refund
     require!(
-        ctx.accounts.buyer.key() == escrow.buyer,
-        EscrowError::InvalidBuyer
+        escrow.status == EscrowStatus::Initialized || escrow.status == EscrowStatus::Funded,
+        EscrowError::InvalidEscrowStatus
     );
     
-    // Refund if payment was made
-    if escrow.payment_amount > 0 {
-        let escrow_key = escrow.key();
-        let bump = ctx.bumps.vault;
-        let seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+    // Validate the buyer or creator is the one cancelling
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == escrow.buyer || authority == escrow.creator,
+        EscrowError::Unauthorized
+    );
+    
+    let escrow_key = escrow.key();
+    let bump = ctx.bumps.vault;
+    let seeds = &[
+        b"vault".as_ref(),
+        escrow_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let mut refunded_amount: u64 = 0;
+    
+    // Refund SPL tokens and close the vault token account
+    // The token vault only ever holds this escrow's payment, so the full balance is returned
+    if let Some(payment_token_mint) = escrow.payment_token_mint {
+        let vault_token_info = ctx.accounts.vault_token_account.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
         
-        if escrow.payment_token_mint.is_none() {
-            // Refund SOL
-            **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= escrow.payment_amount;
-            **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += escrow.payment_amount;
-            
-            msg!("Refunded {} lamports to buyer", escrow.payment_amount);
-        } else {
-            // Refund SPL tokens
-            // Validate that token accounts are provided
+        validate_token_program(&token_program)?;
+        require!(
+            ctx.accounts.payment_token_mint.key() == payment_token_mint,
+            EscrowError::InvalidPaymentMint
+        );
+        
+        // Payments are always deposited into the vault's associated token account, so any
+        // other account is rejected rather than closing the escrow without a refund
+        validate_associated_token_account(
+            &vault_token_info,
+            &ctx.accounts.vault.key(),
+            &payment_token_mint,
+            &token_program,
+        )?;
+        
+        if vault_token_info.data_is_empty() {
+            // The token vault is only missing if nothing was ever deposited
             require!(
-                ctx.accounts.buyer_token_account.key() != System::id(),
+                escrow.status == EscrowStatus::Initialized,
                 EscrowError::InvalidVault
             );
+        } else {
+            let vault_token = read_token_account(&vault_token_info, &token_program)?;
             require!(
-                ctx.accounts.vault_token_account.key() != System::id(),
+                vault_token.owner == ctx.accounts.vault.key(),
                 EscrowError::InvalidVault
             );
             require!(
-                ctx.accounts.token_program.key() == anchor_spl::token::ID,
-                EscrowError::InvalidVault
+                vault_token.mint == payment_token_mint,
+                EscrowError::InvalidPaymentMint
             );
             
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
-                        from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: ctx.accounts.buyer_token_account.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
+            if vault_token.amount > 0 {
+                let buyer_token_info = ctx.accounts.buyer_token_account.to_account_info();
+                let buyer_token = read_token_account(&buyer_token_info, &token_program)
+                    .map_err(|_| EscrowError::InvalidBuyer)?;
+                require!(
+                    buyer_token.owner == escrow.buyer,
+                    EscrowError::InvalidBuyer
+                );
+                require!(
+                    buyer_token.mint == payment_token_mint,
+                    EscrowError::InvalidPaymentMint
+                );
+                
+                transfer_tokens(
+                    &token_program,
+                    &vault_token_info,
+                    &ctx.accounts.payment_token_mint.to_account_info(),
+                    &buyer_token_info,
+                    &ctx.accounts.vault.to_account_info(),
+                    vault_token.amount,
                     signer_seeds,
-                ),
-                escrow.payment_amount,
+                )?;
+                
+                refunded_amount = vault_token.amount;
+                msg!("Refunded {} tokens to buyer", vault_token.amount);
+            }
+            
+            // Token-2022 accounts holding withheld transfer fees cannot be closed
+            harvest_withheld_fees(
+                &token_program,
+                &ctx.accounts.payment_token_mint.to_account_info(),
+                &vault_token_info,
             )?;
             
-            msg!("Refunded {} tokens to buyer", escrow.payment_amount);
+            // Close the emptied token vault, returning its rent to the buyer
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program,
+                CloseAccount {
+                    account: vault_token_info,
+                    destination: ctx.accounts.buyer.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+            
+            msg!("Closed vault token account");
         }
     }
     
+    // Refund SOL held by the vault PDA through a system transfer signed by the vault seeds
+    // Draining every lamport also closes the vault account
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.buyer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            vault_lamports,
+        )?;
+        
+        if escrow.payment_token_mint.is_none() {
+            refunded_amount = vault_lamports;
+        }
+        msg!("Refunded {} lamports to buyer", vault_lamports);
+    }
+    
     // Update escrow status
     escrow.status = EscrowStatus::Cancelled;
     
-    msg!("Escrow cancelled for buyer: {}", ctx.accounts.buyer.key());
+    msg!("Escrow cancelled for buyer: {} by: {}", ctx.accounts.buyer.key(), authority);
+    
+    emit_cpi!(EscrowCancelled {
+        escrow: escrow_key,
+        buyer: escrow.buyer,
+        cancelled_by: authority,
+        payment_token_mint: escrow.payment_token_mint,
+        refunded_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CancelEscrow<'info> {
-    /// The buyer cancelling the escrow
-    #[account(mut)]
-    pub buyer: Signer<'info>,
+    /// The buyer cancelling or the creator refunding the escrow
+    pub authority: Signer<'info>,
+    
+    /// The buyer receiving the refund and escrow rent
+    /// CHECK: Must match the escrow buyer
+    #[account(
+        mut,
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
     
     /// Escrow state PDA
     #[account(
@@ -112,17 +209,22 @@ pub struct CancelEscrow<'info> {
     )]
     pub vault: UncheckedAccount<'info>,
     
+    /// Payment token mint (System::id() for SOL; writable so withheld Token-2022 fees can be harvested)
+    /// CHECK: Must match the escrow payment mint when SPL refund is needed
+    #[account(mut)]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
     /// Buyer's SPL token account (for SPL refunds)
     /// CHECK: Optional account, validated when SPL refund is needed
     #[account(mut)]
     pub buyer_token_account: UncheckedAccount<'info>,
     
-    /// Vault's SPL token account (for SPL refunds)
-    /// CHECK: Optional account, validated when SPL refund is needed
+    /// Vault's associated token account (for SPL refunds, closed to the buyer)
+    /// CHECK: Required to be the vault's associated token account for SPL escrows
     #[account(mut)]
     pub vault_token_account: UncheckedAccount<'info>,
     
-    /// Token program (for SPL refunds)
+    /// Token program, SPL Token or Token-2022 (for SPL refunds)
     /// CHECK: Optional account, validated when SPL refund is needed
     pub token_program: UncheckedAccount<'info>,
     
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+
+/// Close a completed escrow and its vaults, returning all rent to the buyer
+/// Permissionless - the purchase itself is recorded in the buyer's access record
+pub fn close_completed_escrow(ctx: Context<CloseCompletedEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_state;
+    
+    // Settled escrows are still needed for refunds and payouts
+    require!(
+        escrow.status == EscrowStatus::Completed,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    let escrow_key = escrow.key();
+    let bump = ctx.bumps.vault;
+    let seeds = &[
+        b"vault".as_ref(),
+        escrow_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    // Settlement emptied the token vault; close it so its rent returns to the buyer too
+    if let Some(payment_token_mint) = escrow.payment_token_mint {
+        TokenVaultClose {
+            buyer: ctx.accounts.buyer.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+            buyer_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+            vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        }
+        .execute(payment_token_mint, escrow.buyer, signer_seeds)?;
+    }
+    
+    // Settlement moved the whole payment out of the SOL vault; sweep anything sent there since
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.buyer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            vault_lamports,
+        )?;
+    }
+    
+    msg!("Completed escrow closed: {}, rent returned to buyer: {}",
+        escrow_key, ctx.accounts.buyer.key());
+    
+    emit_cpi!(EscrowClosed {
+        escrow: escrow_key,
+        buyer: escrow.buyer,
+        status: escrow.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseCompletedEscrow<'info> {
+    /// The buyer receiving the escrow and vault rent
+    /// CHECK: Must match the escrow buyer
+    #[account(
+        mut,
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        close = buyer,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Vault PDA, owner of the vault token account
+    /// CHECK: Vault is a PDA derived from escrow state
+    #[account(
+        mut,
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL; writable so withheld Token-2022 fees can be harvested)
+    /// CHECK: Must match the escrow payment mint for SPL escrows
+    #[account(mut)]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (receives tokens sent to the vault after settlement)
+    /// CHECK: Optional account, validated when the token vault is not empty
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Vault's associated token account (for SPL escrows, closed to the buyer)
+    /// CHECK: Required to be the vault's associated token account for SPL escrows
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Token program, SPL Token or Token-2022 (for SPL escrows)
+    /// CHECK: Optional account, validated for SPL escrows
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+
+/// Close an escrow that was never paid for once its checkout window has passed, along with its token vault
+/// Permissionless - anyone can clean up abandoned checkouts; all rent returns to the buyer
+pub fn close_expired_escrow(ctx: Context<CloseExpiredEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_state;
+    
+    // Only unfunded escrows: funded ones hold a payment and go through `cancel_escrow`
+    require!(
+        escrow.status == EscrowStatus::Initialized,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    // Validate the checkout window has passed
+    let clock = Clock::get()?;
+    require!(
+        escrow.is_expired(clock.unix_timestamp),
+        EscrowError::EscrowNotExpired
+    );
+    
+    // SPL escrows open their token vault with `initialize_escrow`; close it too so its rent is not stranded
+    if let Some(payment_token_mint) = escrow.payment_token_mint {
+        let escrow_key = escrow.key();
+        let seeds = &[
+            b"vault".as_ref(),
+            escrow_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        
+        TokenVaultClose {
+            buyer: ctx.accounts.buyer.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+            buyer_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+            vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        }
+        .execute(payment_token_mint, escrow.buyer, &[&seeds[..]])?;
+    }
+    
+    msg!("Expired escrow closed: {}, rent returned to buyer: {}", 
+        escrow.key(), ctx.accounts.buyer.key());
+    
+    emit_cpi!(EscrowClosed {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        status: escrow.status,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseExpiredEscrow<'info> {
+    /// The buyer receiving the escrow and vault rent
+    /// CHECK: Must match the escrow buyer
+    #[account(
+        mut,
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        close = buyer,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Vault PDA, owner of the vault token account
+    /// CHECK: Vault is a PDA derived from escrow state
+    #[account(
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL; writable so withheld Token-2022 fees can be harvested)
+    /// CHECK: Must match the escrow payment mint for SPL escrows
+    #[account(mut)]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (receives tokens sent to the vault outside a purchase)
+    /// CHECK: Optional account, validated when the token vault is not empty
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Vault's associated token account (for SPL escrows, closed to the buyer if it exists)
+    /// CHECK: Optional account, required to be the vault's associated token account for SPL escrows
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Token program, SPL Token or Token-2022 (for SPL escrows)
+    /// CHECK: Optional account, validated for SPL escrows
+    pub token_program: UncheckedAccount<'info>,
+}
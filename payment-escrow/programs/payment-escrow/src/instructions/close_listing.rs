@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Close a listing and return its rent to the creator
+pub fn close_listing(ctx: Context<CloseListing>) -> Result<()> {
+    msg!("Listing closed for creator: {}, content_id: {:?}", 
+        ctx.accounts.creator.key(), ctx.accounts.listing.content_id);
+    
+    emit_cpi!(ListingClosed {
+        listing: ctx.accounts.listing.key(),
+        creator: ctx.accounts.creator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseListing<'info> {
+    /// The creator who owns the listing
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA account
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::Unauthorized,
+        close = creator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
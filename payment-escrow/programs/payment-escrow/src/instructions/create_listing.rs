@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use access_mint::state::AccessMintState;
+use distribution::state::SplitState;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Create a listing that fixes the sale terms for a piece of content
+pub fn create_listing(
+    ctx: Context<CreateListing>,
+    content_id: [u8; 32],
+    price: u64,
+    payment_token_mint: Option<Pubkey>,
+    config: ListingConfig,
+    seed: u64,
+) -> Result<()> {
+    require!(price > 0, EscrowError::InvalidPrice);
+    config.validate()?;
+    
+    // Listings must be priced in the currency their split accepts
+    require!(
+        ctx.accounts.split_state.payment_token_mint == payment_token_mint.unwrap_or(System::id()),
+        EscrowError::InvalidPaymentMint
+    );
+    
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    // Initialize listing
+    listing.creator = ctx.accounts.creator.key();
+    listing.content_id = content_id;
+    listing.price = price;
+    listing.payment_token_mint = payment_token_mint;
+    listing.access_mint_state = ctx.accounts.access_mint_state.key();
+    listing.split_state = ctx.accounts.split_state.key();
+    listing.is_active = true;
+    listing.paused_by_admin = false;
+    listing.apply_config(&config);
+    listing.seed = seed;
+    listing.created_ts = clock.unix_timestamp;
+    listing.updated_ts = clock.unix_timestamp;
+    listing.bump = ctx.bumps.listing;
+    
+    msg!("Listing created for creator: {}, content_id: {:?}, price: {}, config: {:?}", 
+        listing.creator, content_id, price, config);
+    
+    emit_cpi!(ListingCreated {
+        listing: listing.key(),
+        creator: listing.creator,
+        content_id,
+        seed,
+        price,
+        payment_token_mint,
+        access_mint_state: listing.access_mint_state,
+        split_state: listing.split_state,
+        config,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(content_id: [u8; 32], price: u64, payment_token_mint: Option<Pubkey>, config: ListingConfig, seed: u64)]
+pub struct CreateListing<'info> {
+    /// The creator selling the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access mint state for the content (must belong to the creator)
+    #[account(
+        constraint = access_mint_state.creator == creator.key() @ EscrowError::InvalidAccessMintState,
+        constraint = access_mint_state.content_id == content_id @ EscrowError::InvalidAccessMintState,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Split state for the content (must belong to the creator)
+    #[account(
+        constraint = split_state.creator == creator.key() @ EscrowError::InvalidSplitState,
+        constraint = split_state.content_id == content_id @ EscrowError::InvalidSplitState,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Listing PDA account
+    #[account(
+        init,
+        payer = creator,
+        space = Listing::LEN,
+        seeds = [
+            Listing::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
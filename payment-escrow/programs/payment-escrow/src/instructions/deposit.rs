@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use access_mint::state::AccessMintState;
+use distribution::state::PlatformConfig;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Deposit the buyer's payment into the escrow vault
+/// Funds stay in the vault until the escrow is settled, cancelled or refunded
+pub fn deposit(ctx: Context<Deposit>, payment_amount: u64) -> Result<()> {
+    // Emergency stop: no payments while the platform is paused
+    require!(
+        !ctx.accounts.platform_config.paused,
+        EscrowError::PlatformPaused
+    );
+    
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    // Validate escrow status
+    require!(
+        escrow.status == EscrowStatus::Initialized,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    // Validate the checkout window has not passed
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !escrow.is_expired(now),
+        EscrowError::EscrowExpired
+    );
+    
+    // Validate payment amount matches price
+    require!(
+        payment_amount == escrow.price,
+        EscrowError::InvalidPaymentAmount
+    );
+    
+    // Validate sale terms against the creator's listing (rejects listings paused by the admin)
+    escrow.validate_listing(ctx.accounts.listing.key(), &ctx.accounts.listing)?;
+    
+    // Refuse payment for limited editions that are already sold out
+    require!(
+        !ctx.accounts.access_mint_state.is_sold_out(),
+        EscrowError::SoldOut
+    );
+    
+    PaymentTransfer {
+        buyer: ctx.accounts.buyer.to_account_info(),
+        vault: ctx.accounts.vault.to_account_info(),
+        payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+        buyer_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+        vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    }
+    .pay(escrow.payment_token_mint, payment_amount)?;
+    
+    // Update escrow state
+    escrow.payment_amount = payment_amount;
+    escrow.status = EscrowStatus::Funded;
+    
+    msg!("Payment of {} deposited by buyer: {}", payment_amount, ctx.accounts.buyer.key());
+    
+    emit_cpi!(PaymentReceived {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        payment_token_mint: escrow.payment_token_mint,
+        amount: payment_amount,
+        renewal: false,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+/// Accounts used to move a payment from the buyer into the escrow vault
+/// Shared by `deposit` and `buy_and_mint`
+pub(crate) struct PaymentTransfer<'info> {
+    pub buyer: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub payment_token_mint: AccountInfo<'info>,
+    pub buyer_token_account: AccountInfo<'info>,
+    pub vault_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> PaymentTransfer<'info> {
+    /// Transfer `amount` of SOL (no mint) or SPL / Token-2022 tokens into the vault
+    /// With a transfer-fee mint the vault receives `amount` minus the fee
+    pub fn pay(&self, payment_token_mint: Option<Pubkey>, amount: u64) -> Result<()> {
+        match payment_token_mint {
+            None => {
+                // SOL payment
+                transfer(
+                    CpiContext::new(
+                        self.system_program.clone(),
+                        Transfer {
+                            from: self.buyer.clone(),
+                            to: self.vault.clone(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+            Some(mint) => {
+                // SPL token payment (legacy token program or Token-2022)
+                validate_token_program(&self.token_program)?;
+                require!(
+                    self.payment_token_mint.key() == mint,
+                    EscrowError::InvalidPaymentMint
+                );
+                validate_payment_mint(&self.payment_token_mint)?;
+                validate_associated_token_account(
+                    &self.vault_token_account,
+                    &self.vault.key(),
+                    &mint,
+                    &self.token_program,
+                )?;
+                
+                let vault_token = read_token_account(&self.vault_token_account, &self.token_program)?;
+                require!(
+                    vault_token.owner == self.vault.key() && vault_token.mint == mint,
+                    EscrowError::InvalidVault
+                );
+                
+                transfer_tokens(
+                    &self.token_program,
+                    &self.buyer_token_account,
+                    &self.payment_token_mint,
+                    &self.vault_token_account,
+                    &self.buyer,
+                    amount,
+                    &[],
+                )?;
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    /// The buyer making the payment
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::InvalidBuyer,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Listing the escrow was opened against
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config (distribution program) holding the global pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Access mint state of the listing (checked for remaining supply)
+    #[account(
+        constraint = access_mint_state.key() == listing.access_mint_state @ EscrowError::InvalidAccessMintState,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Vault PDA to hold SOL payments
+    /// CHECK: Vault is a PDA derived from escrow state
+    #[account(
+        mut,
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the escrow payment mint; validated when SPL payment is used
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Token program, SPL Token or Token-2022 (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdateListing;
+use crate::errors::*;
+use crate::events::*;
+
+/// End the sale now, ahead of any scheduled end
+/// Escrows that are already funded can still be settled or cancelled
+pub fn end_sale(ctx: Context<UpdateListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    require!(
+        !listing.sale_ended(clock.unix_timestamp),
+        EscrowError::SaleEnded
+    );
+    
+    // Move the end forward; a sale scheduled to start later simply never opens
+    listing.sale_start_ts = listing.sale_start_ts.min(clock.unix_timestamp);
+    listing.sale_end_ts = clock.unix_timestamp;
+    listing.updated_ts = clock.unix_timestamp;
+    
+    msg!("Sale ended for listing: {}", listing.key());
+    
+    emit_cpi!(ListingStatusChanged {
+        listing: listing.key(),
+        is_active: listing.is_active,
+        paused_by_admin: listing.paused_by_admin,
+        sale_end_ts: listing.sale_end_ts,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
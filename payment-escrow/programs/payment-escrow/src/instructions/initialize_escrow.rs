@@ -1,49 +1,98 @@
 use anchor_lang::prelude::*;
+use distribution::state::PlatformConfig;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
 /// Initialize a new escrow account for a purchase
+/// Price, payment mint, creator and refund window are taken from the listing, not the buyer
 pub fn initialize_escrow(
     ctx: Context<InitializeEscrow>,
-    content_id: [u8; 32],
-    price: u64,
-    payment_token_mint: Option<Pubkey>,
     seed: u64,
 ) -> Result<()> {
-    require!(price > 0, EscrowError::InvalidPrice);
+    // Emergency stop: no new purchases while the platform is paused
+    require!(
+        !ctx.accounts.platform_config.paused,
+        EscrowError::PlatformPaused
+    );
     
-    let escrow = &mut ctx.accounts.escrow_state;
+    let listing = &ctx.accounts.listing;
     let clock = Clock::get()?;
     
+    listing.validate_sale_open(clock.unix_timestamp)?;
+    require!(listing.price > 0, EscrowError::InvalidPrice);
+    
+    let escrow = &mut ctx.accounts.escrow_state;
+    
     // Initialize escrow state
     escrow.buyer = ctx.accounts.buyer.key();
-    escrow.creator = ctx.accounts.creator.key();
-    escrow.content_id = content_id;
-    escrow.price = price;
-    escrow.payment_token_mint = payment_token_mint;
+    escrow.creator = listing.creator;
+    escrow.listing = listing.key();
+    escrow.split_state = listing.split_state;
+    escrow.content_id = listing.content_id;
+    escrow.price = listing.price;
+    escrow.payment_token_mint = listing.payment_token_mint;
     escrow.payment_amount = 0;
+    escrow.settled_amount = 0;
     escrow.access_mint_address = None;
     escrow.created_ts = clock.unix_timestamp;
+    escrow.expires_at = clock
+        .unix_timestamp
+        .checked_add(EscrowState::CHECKOUT_TTL_SECS)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    escrow.refund_window_secs = listing.refund_window_secs;
+    escrow.settle_after = 0;
     escrow.seed = seed;
     escrow.status = EscrowStatus::Initialized;
     escrow.bump = ctx.bumps.escrow_state;
     
     msg!("Escrow initialized for buyer: {}, creator: {}, content_id: {:?}, price: {}", 
-        escrow.buyer, escrow.creator, content_id, price);
+        escrow.buyer, escrow.creator, escrow.content_id, escrow.price);
+    
+    emit_cpi!(EscrowInitialized {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        creator: escrow.creator,
+        listing: escrow.listing,
+        split_state: escrow.split_state,
+        content_id: escrow.content_id,
+        price: escrow.price,
+        payment_token_mint: escrow.payment_token_mint,
+        seed,
+        expires_at: escrow.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(content_id: [u8; 32], price: u64, payment_token_mint: Option<Pubkey>, seed: u64)]
+#[instruction(seed: u64)]
 pub struct InitializeEscrow<'info> {
     /// The buyer initiating the purchase
     #[account(mut)]
     pub buyer: Signer<'info>,
     
-    /// The creator who will receive payment
-    /// CHECK: Creator account doesn't need to be validated beyond being a valid pubkey
-    pub creator: UncheckedAccount<'info>,
+    /// Listing being purchased
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config (distribution program) holding the global pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
     
     /// Escrow state PDA account
     #[account(
@@ -53,7 +102,7 @@ pub struct InitializeEscrow<'info> {
         seeds = [
             EscrowState::SEED_PREFIX,
             buyer.key().as_ref(),
-            content_id.as_ref(),
+            listing.content_id.as_ref(),
             seed.to_le_bytes().as_ref(),
         ],
         bump
@@ -1,7 +1,35 @@
+pub mod create_listing;
+pub mod update_listing;
+pub mod pause_sale;
+pub mod resume_sale;
+pub mod end_sale;
+pub mod set_listing_paused;
+pub mod close_listing;
 pub mod initialize_escrow;
+pub mod deposit;
+pub mod settle;
 pub mod buy_and_mint;
+pub mod renew;
 pub mod cancel_escrow;
+pub mod request_refund;
+pub mod release_payout;
+pub mod close_expired_escrow;
+pub mod close_completed_escrow;
 
+pub use create_listing::*;
+pub use update_listing::*;
+pub use pause_sale::*;
+pub use resume_sale::*;
+pub use end_sale::*;
+pub use set_listing_paused::*;
+pub use close_listing::*;
 pub use initialize_escrow::*;
+pub use deposit::*;
+pub use settle::*;
 pub use buy_and_mint::*;
+pub use renew::*;
 pub use cancel_escrow::*;
+pub use request_refund::*;
+pub use release_payout::*;
+pub use close_expired_escrow::*;
+pub use close_completed_escrow::*;
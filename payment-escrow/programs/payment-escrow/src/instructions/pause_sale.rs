@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdateListing;
+use crate::events::*;
+
+/// Stop accepting purchases until the creator resumes the sale
+/// Escrows that are already funded can still be settled or cancelled
+pub fn pause_sale(ctx: Context<UpdateListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    listing.is_active = false;
+    listing.updated_ts = clock.unix_timestamp;
+    
+    msg!("Sale paused for listing: {}", listing.key());
+    
+    emit_cpi!(ListingStatusChanged {
+        listing: listing.key(),
+        is_active: listing.is_active,
+        paused_by_admin: listing.paused_by_admin,
+        sale_end_ts: listing.sale_end_ts,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
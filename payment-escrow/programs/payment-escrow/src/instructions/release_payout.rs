@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use distribution::{program::Distribution, state::{PlatformConfig, SplitState}};
+use crate::instructions::Payout;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Credit a settled purchase to the split recipients once its refund window has closed
+/// Permissionless - anyone can crank the payout after `settle_after`
+pub fn release_payout(ctx: Context<ReleasePayout>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    // Validate escrow status
+    require!(
+        escrow.status == EscrowStatus::Settled,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    // Validate the refund window has closed
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= escrow.settle_after,
+        EscrowError::RefundWindowOpen
+    );
+    
+    Payout {
+        escrow_authority: ctx.accounts.escrow_authority.to_account_info(),
+        escrow_authority_bump: ctx.bumps.escrow_authority,
+        distribution_program: ctx.accounts.distribution_program.to_account_info(),
+        split_state: ctx.accounts.split_state.to_account_info(),
+        platform_config: ctx.accounts.platform_config.to_account_info(),
+        distribution_vault: ctx.accounts.distribution_vault.to_account_info(),
+        distribution_vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+        distribution_event_authority: ctx.accounts.distribution_event_authority.to_account_info(),
+    }
+    .release(escrow.settled_amount)?;
+    
+    escrow.status = EscrowStatus::Completed;
+    
+    msg!("Payout released for escrow: {}", escrow.key());
+    
+    emit_cpi!(PayoutReleased {
+        escrow: escrow.key(),
+        split_state: escrow.split_state,
+        amount: escrow.settled_amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleasePayout<'info> {
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Escrow authority PDA (authorized distributor)
+    /// CHECK: PDA derived from the program-wide authority seed
+    #[account(
+        seeds = [EscrowState::AUTHORITY_SEED],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state PDA that received the payment
+    #[account(
+        mut,
+        constraint = split_state.key() == escrow_state.split_state @ EscrowError::InvalidSplitState,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Platform config (distribution program) holding the global pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Distribution vault PDA holding the payment
+    /// CHECK: Validated by distribution program via CPI
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Distribution vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated by distribution program when SPL payment is used
+    pub distribution_vault_token_account: UncheckedAccount<'info>,
+    
+    /// Distribution program's event authority PDA
+    /// CHECK: Validated by distribution program via CPI
+    pub distribution_event_authority: UncheckedAccount<'info>,
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{purchase, BuyAndMint};
+
+/// Pay for another access period on a time-limited access mint
+/// Takes the same accounts as `buy_and_mint`; the buyer must already hold an access record.
+/// Renewals are final: the payment is distributed immediately, without a refund window
+pub fn renew(ctx: Context<BuyAndMint>, payment_amount: u64) -> Result<()> {
+    purchase(ctx, payment_amount, true)?;
+    
+    msg!("Renewal completed successfully");
+    
+    Ok(())
+}
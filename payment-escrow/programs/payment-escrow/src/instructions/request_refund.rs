@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use anchor_spl::token::{Mint, Token};
+use access_mint::{
+    program::AccessMint,
+    cpi::accounts::RevokeAccess as RevokeAccessAccounts,
+    cpi::revoke_access,
+    state::{AccessMintState, TransferMode},
+};
+use distribution::{
+    program::Distribution,
+    cpi::accounts::Refund as RefundAccounts,
+    cpi::refund,
+    state::SplitState,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Refund a settled purchase while its refund window is open
+/// Burns the buyer's access token and returns the held payment from the distribution vault
+pub fn request_refund(ctx: Context<RequestRefund>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    // Validate escrow status
+    require!(
+        escrow.status == EscrowStatus::Settled,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    // Validate the refund window is still open
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < escrow.settle_after,
+        EscrowError::RefundWindowClosed
+    );
+    
+    // Validate the access mint belongs to this purchase
+    require!(
+        escrow.access_mint_address == Some(ctx.accounts.access_mint.key())
+            && ctx.accounts.access_mint_state.mint == ctx.accounts.access_mint.key(),
+        EscrowError::InvalidAccessMintState
+    );
+    
+    let authority_seeds = &[
+        EscrowState::AUTHORITY_SEED,
+        &[ctx.bumps.escrow_authority],
+    ];
+    let authority_signer_seeds = &[&authority_seeds[..]];
+    
+    // Burn the buyer's access token (buyer signs as owner, escrow authority as minter)
+    revoke_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            RevokeAccessAccounts {
+                buyer: ctx.accounts.buyer.to_account_info(),
+                minter: ctx.accounts.escrow_authority.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.access_mint_authority.to_account_info(),
+                buyer_token_account: (ctx.accounts.access_mint_state.transfer_mode != TransferMode::RecordOnly)
+                    .then(|| ctx.accounts.buyer_access_token_account.to_account_info()),
+                access_record: ctx.accounts.access_record.to_account_info(),
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                event_authority: ctx.accounts.access_mint_event_authority.to_account_info(),
+                program: ctx.accounts.access_mint_program.to_account_info(),
+            },
+            authority_signer_seeds,
+        ),
+    )?;
+    
+    // Return the held payment from the distribution vault
+    refund(
+        CpiContext::new_with_signer(
+            ctx.accounts.distribution_program.to_account_info(),
+            RefundAccounts {
+                authority: ctx.accounts.escrow_authority.to_account_info(),
+                split_state: ctx.accounts.split_state.to_account_info(),
+                vault: ctx.accounts.distribution_vault.to_account_info(),
+                recipient: ctx.accounts.buyer.to_account_info(),
+                payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+                vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+                recipient_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                event_authority: ctx.accounts.distribution_event_authority.to_account_info(),
+                program: ctx.accounts.distribution_program.to_account_info(),
+            },
+            authority_signer_seeds,
+        ),
+        escrow.settled_amount,
+    )?;
+    
+    escrow.status = EscrowStatus::Refunded;
+    
+    msg!("Purchase refunded to buyer: {}", ctx.accounts.buyer.key());
+    
+    emit_cpi!(EscrowRefunded {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        split_state: escrow.split_state,
+        payment_token_mint: escrow.payment_token_mint,
+        refunded_amount: escrow.settled_amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestRefund<'info> {
+    /// The buyer requesting the refund
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::InvalidBuyer,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Escrow authority PDA (authorized minter and distributor)
+    /// CHECK: PDA derived from the program-wide authority seed
+    #[account(
+        seeds = [EscrowState::AUTHORITY_SEED],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state PDA (revoking access frees the purchase's edition)
+    #[account(mut)]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Access mint authority PDA (thaws soulbound access tokens before burning)
+    /// CHECK: Validated by access mint program via CPI
+    pub access_mint_authority: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account
+    /// CHECK: Validated by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Buyer's access record PDA (expired on refund)
+    /// CHECK: Validated by access mint program via CPI
+    #[account(mut)]
+    pub access_record: UncheckedAccount<'info>,
+    
+    /// Access mint program's event authority PDA
+    /// CHECK: Validated by access mint program via CPI
+    pub access_mint_event_authority: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state PDA that received the payment
+    #[account(
+        mut,
+        constraint = split_state.key() == escrow_state.split_state @ EscrowError::InvalidSplitState,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution vault PDA holding the payment
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL)
+    /// CHECK: Validated by distribution program when SPL payment is used
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Distribution vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub distribution_vault_token_account: UncheckedAccount<'info>,
+    
+    /// Buyer's payment token account (for SPL payments)
+    /// CHECK: Optional account, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Token program, SPL Token or Token-2022 (for SPL payments)
+    /// CHECK: Optional account, validated by distribution program when SPL payment is used
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// Distribution program's event authority PDA
+    /// CHECK: Validated by distribution program via CPI
+    pub distribution_event_authority: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
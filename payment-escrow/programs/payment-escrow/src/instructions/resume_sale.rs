@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdateListing;
+use crate::errors::*;
+use crate::events::*;
+
+/// Accept purchases again after `pause_sale`
+/// A sale that has ended must be rescheduled with `update_listing` instead
+pub fn resume_sale(ctx: Context<UpdateListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    require!(
+        !listing.sale_ended(clock.unix_timestamp),
+        EscrowError::SaleEnded
+    );
+    
+    listing.is_active = true;
+    listing.updated_ts = clock.unix_timestamp;
+    
+    msg!("Sale resumed for listing: {}", listing.key());
+    
+    emit_cpi!(ListingStatusChanged {
+        listing: listing.key(),
+        is_active: listing.is_active,
+        paused_by_admin: listing.paused_by_admin,
+        sale_end_ts: listing.sale_end_ts,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use distribution::state::PlatformConfig;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Pause or unpause a single listing (platform admin only)
+/// Independent of the creator's `pause_sale`; refunds, cancellations and claims keep working
+pub fn set_listing_paused(ctx: Context<SetListingPaused>, paused: bool) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    listing.paused_by_admin = paused;
+    listing.updated_ts = clock.unix_timestamp;
+    
+    msg!("Listing {} paused by admin: {}", listing.key(), paused);
+    
+    emit_cpi!(ListingStatusChanged {
+        listing: listing.key(),
+        is_active: listing.is_active,
+        paused_by_admin: listing.paused_by_admin,
+        sale_end_ts: listing.sale_end_ts,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetListingPaused<'info> {
+    /// Platform admin recorded in the platform config
+    #[account(
+        constraint = admin.key() == platform_config.admin @ EscrowError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+    
+    /// Platform config (distribution program)
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA account
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,599 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint::{
+    program::AccessMint,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::accounts::RenewAccess as RenewAccessAccounts,
+    cpi::{mint_access, renew_access},
+    state::{AccessMintState, TransferMode},
+};
+use distribution::{
+    program::Distribution,
+    cpi::accounts::{Distribute as DistributeAccounts, Hold as HoldAccounts},
+    cpi::{distribute, hold, release},
+    state::{PlatformConfig, SplitState},
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+
+/// Settle a funded escrow - mints access to the buyer and distributes the payment
+/// (or holds it until the refund window closes)
+/// Can be called by the buyer or the creator; approval-based listings require the creator
+pub fn settle(ctx: Context<Settle>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_state;
+    let payer = ctx.accounts.payer.key();
+    
+    // Validate escrow status
+    require!(
+        escrow.status == EscrowStatus::Funded,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    // Validate who is settling
+    require!(
+        payer == escrow.buyer || payer == escrow.creator,
+        EscrowError::Unauthorized
+    );
+    if ctx.accounts.listing.requires_approval {
+        require!(payer == escrow.creator, EscrowError::ApprovalRequired);
+    }
+    
+    let settlement = Settlement {
+        escrow_state: &mut ctx.accounts.escrow_state,
+        access_mint_state: &ctx.accounts.access_mint_state,
+        split_state: &ctx.accounts.split_state,
+        payer: ctx.accounts.payer.to_account_info(),
+        buyer: ctx.accounts.buyer.to_account_info(),
+        vault: ctx.accounts.vault.to_account_info(),
+        vault_bump: ctx.bumps.vault,
+        vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+        payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        escrow_authority: ctx.accounts.escrow_authority.to_account_info(),
+        escrow_authority_bump: ctx.bumps.escrow_authority,
+        access_mint_program: ctx.accounts.access_mint_program.to_account_info(),
+        access_mint: ctx.accounts.access_mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        buyer_access_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+        access_record: ctx.accounts.access_record.to_account_info(),
+        access_mint_event_authority: ctx.accounts.access_mint_event_authority.to_account_info(),
+        access_token_program: ctx.accounts.access_token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        distribution_program: ctx.accounts.distribution_program.to_account_info(),
+        platform_config: ctx.accounts.platform_config.to_account_info(),
+        distribution_vault: ctx.accounts.distribution_vault.to_account_info(),
+        distribution_vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+        distribution_event_authority: ctx.accounts.distribution_event_authority.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        renewal: false,
+    };
+    
+    settlement.validate(&ctx.accounts.listing)?;
+    settlement.execute()?;
+    
+    msg!("Escrow settled by: {}", payer);
+    
+    let escrow = &ctx.accounts.escrow_state;
+    emit_cpi!(EscrowSettled {
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        split_state: escrow.split_state,
+        access_mint: ctx.accounts.access_mint.key(),
+        settled_amount: escrow.settled_amount,
+        held_until: if escrow.status == EscrowStatus::Settled { escrow.settle_after } else { 0 },
+        renewal: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Accounts used to release a funded escrow to the buyer (access) and recipients (funds)
+/// Shared by `settle` and `buy_and_mint`
+pub(crate) struct Settlement<'a, 'info> {
+    pub escrow_state: &'a mut Account<'info, EscrowState>,
+    pub access_mint_state: &'a Account<'info, AccessMintState>,
+    pub split_state: &'a Account<'info, SplitState>,
+    pub payer: AccountInfo<'info>,
+    pub buyer: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub vault_bump: u8,
+    pub vault_token_account: AccountInfo<'info>,
+    pub payment_token_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub escrow_authority: AccountInfo<'info>,
+    pub escrow_authority_bump: u8,
+    pub access_mint_program: AccountInfo<'info>,
+    pub access_mint: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub buyer_access_token_account: AccountInfo<'info>,
+    pub access_record: AccountInfo<'info>,
+    pub access_mint_event_authority: AccountInfo<'info>,
+    pub access_token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub distribution_program: AccountInfo<'info>,
+    pub platform_config: AccountInfo<'info>,
+    pub distribution_vault: AccountInfo<'info>,
+    pub distribution_vault_token_account: AccountInfo<'info>,
+    pub distribution_event_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// Extend the buyer's existing access instead of minting a new access token
+    pub renewal: bool,
+}
+
+impl<'a, 'info> Settlement<'a, 'info> {
+    /// Validate that the access mint, split and recipients all belong to the escrowed listing
+    /// Must run before any money moves
+    pub fn validate(&self, listing: &Listing) -> Result<()> {
+        let escrow = &self.escrow_state;
+        
+        // Validate that the access mint and split belong to the escrowed content and creator first,
+        // so a state swapped in from another product fails on what differs
+        require!(
+            self.access_mint_state.content_id == escrow.content_id
+                && self.split_state.content_id == escrow.content_id,
+            EscrowError::InvalidContentId
+        );
+        require!(
+            self.access_mint_state.creator == escrow.creator
+                && self.split_state.creator == escrow.creator,
+            EscrowError::InvalidCreator
+        );
+        
+        // Then that they are the exact accounts the listing was created with
+        require!(
+            self.access_mint_state.key() == listing.access_mint_state,
+            EscrowError::InvalidAccessMintState
+        );
+        require!(
+            self.split_state.key() == listing.split_state
+                && self.split_state.key() == escrow.split_state,
+            EscrowError::InvalidSplitState
+        );
+        require!(
+            self.access_mint.key() == self.access_mint_state.mint,
+            EscrowError::InvalidAccessMintState
+        );
+        
+        // Refuse payment for limited editions that are already sold out (renewals mint nothing)
+        require!(
+            self.renewal || !self.access_mint_state.is_sold_out(),
+            EscrowError::SoldOut
+        );
+        
+        // Funds may be held without a distribute CPI, so check the destination vault here
+        let (distribution_vault, _) = Pubkey::find_program_address(
+            &[b"vault", self.split_state.key().as_ref()],
+            &distribution::ID,
+        );
+        require!(
+            self.distribution_vault.key() == distribution_vault,
+            EscrowError::InvalidVault
+        );
+        
+        if let Some(mint) = escrow.payment_token_mint {
+            require!(
+                self.payment_token_mint.key() == mint,
+                EscrowError::InvalidPaymentMint
+            );
+            validate_token_program(&self.token_program)?;
+            
+            let vault_token = read_token_account(&self.vault_token_account, &self.token_program)?;
+            require!(
+                vault_token.owner == self.vault.key() && vault_token.mint == mint,
+                EscrowError::InvalidVault
+            );
+            let distribution_token = read_token_account(&self.distribution_vault_token_account, &self.token_program)?;
+            require!(
+                distribution_token.owner == distribution_vault && distribution_token.mint == mint,
+                EscrowError::InvalidVault
+            );
+        }
+        
+        Ok(())
+    }
+    
+    /// Buyer's access token account for the mint CPI (none for `RecordOnly` access mints)
+    fn access_token_account(&self) -> Option<AccountInfo<'info>> {
+        (self.access_mint_state.transfer_mode != TransferMode::RecordOnly)
+            .then(|| self.buyer_access_token_account.clone())
+    }
+    
+    /// Mint access to the buyer, move the escrowed funds to the distribution vault and distribute them
+    /// If the escrow has a refund window, distribution is deferred and the escrow is left `Settled`
+    pub fn execute(self) -> Result<()> {
+        let buyer_access_token_account = self.access_token_account();
+        let escrow = self.escrow_state;
+        
+        require!(
+            escrow.status == EscrowStatus::Funded,
+            EscrowError::InvalidEscrowStatus
+        );
+        
+        let payment_amount = escrow.payment_amount;
+        
+        // CPI to Access Mint program to mint (or renew) the buyer's access
+        // The escrow authority PDA signs as the authorized minter
+        let authority_seeds = &[
+            EscrowState::AUTHORITY_SEED,
+            &[self.escrow_authority_bump],
+        ];
+        let authority_signer_seeds = &[&authority_seeds[..]];
+        
+        if self.renewal {
+            renew_access(
+                CpiContext::new_with_signer(
+                    self.access_mint_program.clone(),
+                    RenewAccessAccounts {
+                        buyer: self.buyer.clone(),
+                        minter: self.escrow_authority.clone(),
+                        access_mint_state: self.access_mint_state.to_account_info(),
+                        access_record: self.access_record.clone(),
+                        event_authority: self.access_mint_event_authority.clone(),
+                        program: self.access_mint_program.clone(),
+                    },
+                    authority_signer_seeds,
+                ),
+            )?;
+            
+            msg!("Access renewed for buyer: {}", self.buyer.key());
+        } else {
+            mint_access(
+                CpiContext::new_with_signer(
+                    self.access_mint_program.clone(),
+                    AccessMintAccounts {
+                        buyer: self.buyer.clone(),
+                        payer: self.payer.clone(),
+                        minter: self.escrow_authority.clone(),
+                        access_mint_state: self.access_mint_state.to_account_info(),
+                        platform_config: self.platform_config.clone(),
+                        mint: self.access_mint.clone(),
+                        mint_authority: self.mint_authority.clone(),
+                        buyer_token_account: buyer_access_token_account,
+                        access_record: self.access_record.clone(),
+                        token_program: self.access_token_program.clone(),
+                        associated_token_program: self.associated_token_program.clone(),
+                        system_program: self.system_program.clone(),
+                        event_authority: self.access_mint_event_authority.clone(),
+                        program: self.access_mint_program.clone(),
+                    },
+                    authority_signer_seeds,
+                ),
+                escrow.key(),
+                escrow.payment_amount,
+            )?;
+            
+            msg!("Access token minted to buyer: {}", self.buyer.key());
+        }
+        
+        // Store the access mint address in escrow
+        escrow.access_mint_address = Some(self.access_mint.key());
+        
+        // Transfer funds from escrow vault to distribution vault before distributing
+        let escrow_key = escrow.key();
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            escrow_key.as_ref(),
+            &[self.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+        
+        let settled_amount = if escrow.payment_token_mint.is_none() {
+            // SOL payment: Transfer from escrow vault to distribution vault
+            // Use system program transfer to properly handle account creation and rent
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.clone(),
+                    Transfer {
+                        from: self.vault.clone(),
+                        to: self.distribution_vault.clone(),
+                    },
+                    signer_seeds,
+                ),
+                payment_amount,
+            )?;
+            
+            msg!("Transferred {} lamports from escrow vault to distribution vault", payment_amount);
+            
+            payment_amount
+        } else {
+            // SPL token payment: move the whole token vault (the deposit net of any transfer fee)
+            // and measure what the distribution vault actually received
+            let vault_balance = read_token_account(&self.vault_token_account, &self.token_program)?.amount;
+            let balance_before = read_token_account(&self.distribution_vault_token_account, &self.token_program)?.amount;
+            
+            transfer_tokens(
+                &self.token_program,
+                &self.vault_token_account,
+                &self.payment_token_mint,
+                &self.distribution_vault_token_account,
+                &self.vault,
+                vault_balance,
+                signer_seeds,
+            )?;
+            
+            let balance_after = read_token_account(&self.distribution_vault_token_account, &self.token_program)?.amount;
+            let received = balance_after
+                .checked_sub(balance_before)
+                .ok_or(EscrowError::NumericalOverflow)?;
+            
+            msg!("Transferred {} tokens from escrow vault, {} received by distribution vault", vault_balance, received);
+            
+            received
+        };
+        escrow.settled_amount = settled_amount;
+        
+        // Purchases with a refund window keep the funds in the distribution vault
+        // until `release_payout` is called after the window closes
+        if escrow.refund_window_secs > 0 {
+            let clock = Clock::get()?;
+            escrow.settle_after = clock
+                .unix_timestamp
+                .checked_add(escrow.refund_window_secs)
+                .ok_or(EscrowError::NumericalOverflow)?;
+            escrow.status = EscrowStatus::Settled;
+            
+            // Record the held payment so the split cannot credit, refund or sweep it elsewhere
+            Payout {
+                escrow_authority: self.escrow_authority,
+                escrow_authority_bump: self.escrow_authority_bump,
+                distribution_program: self.distribution_program,
+                split_state: self.split_state.to_account_info(),
+                platform_config: self.platform_config,
+                distribution_vault: self.distribution_vault,
+                distribution_vault_token_account: self.distribution_vault_token_account,
+                distribution_event_authority: self.distribution_event_authority,
+            }
+            .hold(settled_amount)?;
+            
+            msg!("Payout held until: {}", escrow.settle_after);
+            
+            return Ok(());
+        }
+        
+        Payout {
+            escrow_authority: self.escrow_authority,
+            escrow_authority_bump: self.escrow_authority_bump,
+            distribution_program: self.distribution_program,
+            split_state: self.split_state.to_account_info(),
+            platform_config: self.platform_config,
+            distribution_vault: self.distribution_vault,
+            distribution_vault_token_account: self.distribution_vault_token_account,
+            distribution_event_authority: self.distribution_event_authority,
+        }
+        .distribute(settled_amount)?;
+        
+        escrow.status = EscrowStatus::Completed;
+        
+        Ok(())
+    }
+}
+
+/// Accounts used to credit funds already in the distribution vault to the split recipients
+/// Shared by `settle`, `buy_and_mint` and `release_payout`
+pub(crate) struct Payout<'info> {
+    pub escrow_authority: AccountInfo<'info>,
+    pub escrow_authority_bump: u8,
+    pub distribution_program: AccountInfo<'info>,
+    pub split_state: AccountInfo<'info>,
+    pub platform_config: AccountInfo<'info>,
+    pub distribution_vault: AccountInfo<'info>,
+    pub distribution_vault_token_account: AccountInfo<'info>,
+    pub distribution_event_authority: AccountInfo<'info>,
+}
+
+impl<'info> Payout<'info> {
+    /// Credit a payment that was not held, via CPI to `distribute`
+    pub fn distribute(self, amount: u64) -> Result<()> {
+        self.credit(amount, false)
+    }
+    
+    /// Credit a payment held since settlement, via CPI to `release`
+    pub fn release(self, amount: u64) -> Result<()> {
+        self.credit(amount, true)
+    }
+    
+    /// Record a payment held for its refund window, via CPI to `hold`
+    pub fn hold(self, amount: u64) -> Result<()> {
+        let authority_seeds = &[
+            EscrowState::AUTHORITY_SEED,
+            &[self.escrow_authority_bump],
+        ];
+        let authority_signer_seeds = &[&authority_seeds[..]];
+        
+        hold(
+            CpiContext::new_with_signer(
+                self.distribution_program.clone(),
+                HoldAccounts {
+                    authority: self.escrow_authority,
+                    split_state: self.split_state,
+                    vault: self.distribution_vault,
+                    vault_token_account: self.distribution_vault_token_account,
+                    event_authority: self.distribution_event_authority,
+                    program: self.distribution_program,
+                },
+                authority_signer_seeds,
+            ),
+            amount,
+        )
+    }
+    
+    /// CPI to the distribution program, signed by the escrow authority as distributor
+    fn credit(self, amount: u64, held: bool) -> Result<()> {
+        let authority_seeds = &[
+            EscrowState::AUTHORITY_SEED,
+            &[self.escrow_authority_bump],
+        ];
+        let authority_signer_seeds = &[&authority_seeds[..]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.distribution_program.clone(),
+            DistributeAccounts {
+                authority: self.escrow_authority,
+                split_state: self.split_state,
+                platform_config: self.platform_config,
+                vault: self.distribution_vault,
+                vault_token_account: self.distribution_vault_token_account,
+                event_authority: self.distribution_event_authority,
+                program: self.distribution_program,
+            },
+            authority_signer_seeds,
+        );
+        if held {
+            release(cpi_ctx, amount)?;
+        } else {
+            distribute(cpi_ctx, amount)?;
+        }
+        
+        msg!("Funds credited to creator, platform, and collaborators");
+        
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    /// Buyer or creator settling the escrow (pays for the buyer's access token account if needed)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// The buyer receiving access
+    /// CHECK: Must match the escrow buyer
+    #[account(
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Escrow state PDA
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Listing the escrow was opened against
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+        constraint = listing.key() == escrow_state.listing @ EscrowError::InvalidListing,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config (distribution program) holding the global pause flag
+    #[account(
+        seeds = [PlatformConfig::SEED],
+        bump = platform_config.bump,
+        seeds::program = distribution::ID,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Vault PDA holding the escrowed SOL
+    /// CHECK: Vault is a PDA derived from escrow state
+    #[account(
+        mut,
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the escrow payment mint; validated when SPL payment is used
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Token program, SPL Token or Token-2022 (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    pub token_program: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Escrow authority PDA (authorized minter and distributor)
+    /// CHECK: PDA derived from the program-wide authority seed
+    #[account(
+        seeds = [EscrowState::AUTHORITY_SEED],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA (checked against the escrow before any transfer)
+    #[account(mut)]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account (will be created if needed; unused for record-only access)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Buyer's access record PDA (created or extended by access mint program)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub access_record: UncheckedAccount<'info>,
+    
+    /// Access mint program's event authority PDA
+    /// CHECK: Validated by access mint program via CPI
+    pub access_mint_event_authority: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state PDA (checked against the escrow before any transfer)
+    #[account(mut)]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// CHECK: Vault is a PDA derived from split_state in the distribution program
+    /// Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Distribution vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub distribution_vault_token_account: UncheckedAccount<'info>,
+    
+    /// Distribution program's event authority PDA
+    /// CHECK: Validated by distribution program via CPI
+    pub distribution_event_authority: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Update the price, approval mode, refund window or sale window of a listing
+/// The payment mint is fixed by the listing's split; use `pause_sale`/`resume_sale` to toggle sales
+/// Escrows opened before the update keep the old price and will be rejected at purchase;
+/// they also keep the refund window they were opened with
+pub fn update_listing(
+    ctx: Context<UpdateListing>,
+    price: u64,
+    config: ListingConfig,
+) -> Result<()> {
+    require!(price > 0, EscrowError::InvalidPrice);
+    config.validate()?;
+    
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    listing.price = price;
+    listing.apply_config(&config);
+    listing.updated_ts = clock.unix_timestamp;
+    
+    msg!("Listing updated: price: {}, config: {:?}", price, config);
+    
+    emit_cpi!(ListingUpdated {
+        listing: listing.key(),
+        price,
+        config,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateListing<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA account
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            listing.seed.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::Unauthorized,
+    )]
+    pub listing: Account<'info, Listing>,
+}
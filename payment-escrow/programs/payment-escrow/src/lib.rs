@@ -6,49 +6,152 @@ declare_id!("2T3AsDRbQdpLWaxEU5vbFXuzRHQnq7JT3wCQCmvdiKmJ");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
+pub mod utils;
 
 use instructions::*;
+use state::ListingConfig;
 
 #[program]
 pub mod payment_escrow {
     use super::*;
 
-    /// Initialize a new escrow for a content purchase
+    /// Create a listing that fixes the price and payment mint for content
     /// 
     /// # Arguments
     /// * `content_id` - 32-byte unique identifier for the content
     /// * `price` - Price in lamports (SOL) or token amount (SPL)
-    /// * `payment_token_mint` - Optional SPL token mint (None for SOL payments)
-    /// * `seed` - Trade nonce for uniqueness (allows multiple purchases)
-    pub fn initialize_escrow(
-        ctx: Context<InitializeEscrow>,
+    /// * `payment_token_mint` - Optional SPL token mint (None for SOL payments); must match the split
+    /// * `config` - Approval mode, refund window (0 = none) and sale window (0 = open-ended)
+    /// * `seed` - Seed for PDA derivation (allows multiple listings per content)
+    pub fn create_listing(
+        ctx: Context<CreateListing>,
         content_id: [u8; 32],
         price: u64,
         payment_token_mint: Option<Pubkey>,
+        config: ListingConfig,
         seed: u64,
     ) -> Result<()> {
-        instructions::initialize_escrow::initialize_escrow(
+        instructions::create_listing::create_listing(
             ctx,
             content_id,
             price,
             payment_token_mint,
+            config,
             seed,
         )
     }
 
-    /// Execute payment and mint access token atomically
+    /// Update the sale terms of a listing (creator only)
+    /// 
+    /// # Arguments
+    /// * `price` - New price in lamports (SOL) or token amount (SPL)
+    /// * `config` - New approval mode, refund window and sale window; the refund window
+    ///   applies to escrows opened after the update
+    pub fn update_listing(
+        ctx: Context<UpdateListing>,
+        price: u64,
+        config: ListingConfig,
+    ) -> Result<()> {
+        instructions::update_listing::update_listing(ctx, price, config)
+    }
+
+    /// Stop accepting purchases on a listing (creator only)
+    pub fn pause_sale(ctx: Context<UpdateListing>) -> Result<()> {
+        instructions::pause_sale::pause_sale(ctx)
+    }
+
+    /// Accept purchases again on a paused listing (creator only)
+    pub fn resume_sale(ctx: Context<UpdateListing>) -> Result<()> {
+        instructions::resume_sale::resume_sale(ctx)
+    }
+
+    /// End a sale immediately, ahead of its scheduled end (creator only)
+    pub fn end_sale(ctx: Context<UpdateListing>) -> Result<()> {
+        instructions::end_sale::end_sale(ctx)
+    }
+
+    /// Pause or unpause a listing (platform admin only)
+    pub fn set_listing_paused(ctx: Context<SetListingPaused>, paused: bool) -> Result<()> {
+        instructions::set_listing_paused::set_listing_paused(ctx, paused)
+    }
+
+    /// Close a listing and reclaim its rent (creator only)
+    pub fn close_listing(ctx: Context<CloseListing>) -> Result<()> {
+        instructions::close_listing::close_listing(ctx)
+    }
+
+    /// Initialize a new escrow for a content purchase
+    /// 
+    /// Price, payment mint and creator are read from the listing; rejected while the platform is paused
     /// 
     /// # Arguments
-    /// * `payment_amount` - Amount to pay (must match escrow price)
-    pub fn buy_and_mint<'info>(
-        ctx: Context<'_, '_, '_, 'info, BuyAndMint<'info>>,
-        payment_amount: u64,
+    /// * `seed` - Trade nonce for uniqueness (allows multiple purchases)
+    pub fn initialize_escrow(
+        ctx: Context<InitializeEscrow>,
+        seed: u64,
     ) -> Result<()> {
+        instructions::initialize_escrow::initialize_escrow(ctx, seed)
+    }
+
+    /// Deposit the buyer's payment into the escrow vault
+    /// 
+    /// # Arguments
+    /// * `payment_amount` - Amount to pay (must match listing and escrow price)
+    pub fn deposit(ctx: Context<Deposit>, payment_amount: u64) -> Result<()> {
+        instructions::deposit::deposit(ctx, payment_amount)
+    }
+
+    /// Settle a funded escrow - mint access and distribute the payment
+    /// Buyer or creator may settle; approval-based listings require the creator
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        instructions::settle::settle(ctx)
+    }
+
+    /// Execute payment and mint access token atomically (rejected while the platform is paused)
+    /// 
+    /// # Arguments
+    /// * `payment_amount` - Amount to pay (must match listing and escrow price)
+    pub fn buy_and_mint(ctx: Context<BuyAndMint>, payment_amount: u64) -> Result<()> {
         instructions::buy_and_mint::buy_and_mint(ctx, payment_amount)
     }
 
+    /// Pay for another access period on a time-limited access mint
+    /// Uses the same accounts as `buy_and_mint`; renewals are paid out immediately
+    /// 
+    /// # Arguments
+    /// * `payment_amount` - Amount to pay (must match listing and escrow price)
+    pub fn renew(ctx: Context<BuyAndMint>, payment_amount: u64) -> Result<()> {
+        instructions::renew::renew(ctx, payment_amount)
+    }
+
     /// Cancel an escrow and refund the buyer
+    /// Signed by the buyer (cancel) or the creator (refund) while not yet settled
     pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
         instructions::cancel_escrow::cancel_escrow(ctx)
     }
+
+    /// Refund a settled purchase while its refund window is open
+    /// Burns the buyer's access token and returns the held payment (buyer only)
+    pub fn request_refund(ctx: Context<RequestRefund>) -> Result<()> {
+        instructions::request_refund::request_refund(ctx)
+    }
+
+    /// Distribute a settled purchase after its refund window has closed
+    /// Permissionless crank
+    pub fn release_payout(ctx: Context<ReleasePayout>) -> Result<()> {
+        instructions::release_payout::release_payout(ctx)
+    }
+
+    /// Close an unfunded escrow whose checkout window has expired
+    /// Permissionless crank; rent returns to the buyer
+    pub fn close_expired_escrow(ctx: Context<CloseExpiredEscrow>) -> Result<()> {
+        instructions::close_expired_escrow::close_expired_escrow(ctx)
+    }
+
+    /// Close a completed escrow and its token vault
+    /// Permissionless crank; rent returns to the buyer
+    pub fn close_completed_escrow(ctx: Context<CloseCompletedEscrow>) -> Result<()> {
+        instructions::close_completed_escrow::close_completed_escrow(ctx)
+    }
 }
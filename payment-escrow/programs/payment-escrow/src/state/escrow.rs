@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+use crate::state::Listing;
 
 /// Escrow State Account - stores purchase metadata
 #[account]
@@ -9,24 +11,42 @@ pub struct EscrowState {
     /// The creator's public key who will receive payment
     pub creator: Pubkey,
     
+    /// Listing this purchase was made against
+    pub listing: Pubkey,
+    
+    /// Split state receiving the payment (copied from the listing)
+    pub split_state: Pubkey,
+    
     /// Content identifier (32 bytes)
     pub content_id: [u8; 32],
     
-    /// Price in lamports or SPL token amount
+    /// Price in lamports or SPL token amount (copied from the listing)
     pub price: u64,
     
     /// Optional payment token mint (None = SOL, Some = SPL token)
     pub payment_token_mint: Option<Pubkey>,
     
-    /// Amount actually paid (should match price)
+    /// Amount deposited into the escrow vault (should match price)
     pub payment_amount: u64,
     
+    /// Amount received by the distribution vault at settlement (net of token transfer fees)
+    pub settled_amount: u64,
+    
     /// Optional access mint address that was created
     pub access_mint_address: Option<Pubkey>,
     
     /// Timestamp when escrow was created
     pub created_ts: i64,
     
+    /// Timestamp after which an unfunded escrow can no longer be paid and may be closed by anyone
+    pub expires_at: i64,
+    
+    /// Refund window in seconds after settlement (copied from the listing, 0 = none)
+    pub refund_window_secs: i64,
+    
+    /// Timestamp after which settled funds can be released to recipients
+    pub settle_after: i64,
+    
     /// Trade nonce for uniqueness (allows multiple purchases)
     pub seed: u64,
     
@@ -39,13 +59,37 @@ pub struct EscrowState {
 
 impl EscrowState {
     /// Size calculation for account allocation
-    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8) 
-    /// + Option<Pubkey> (1 + 32) + u64 (8) + Option<Pubkey> (1 + 32) 
-    /// + i64 (8) + u64 (8) + EscrowStatus (1) + u8 (1)
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 33 + 8 + 33 + 8 + 8 + 1 + 1;
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) 
+    /// + u64 (8) + Option<Pubkey> (1 + 32) + u64 (8) + u64 (8) + Option<Pubkey> (1 + 32) 
+    /// + i64 (8) + i64 (8) + i64 (8) + i64 (8) + u64 (8) + EscrowStatus (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 33 + 8 + 8 + 33 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
     
     /// PDA seed prefix
     pub const SEED_PREFIX: &'static [u8] = b"escrow";
+    
+    /// Seed of the program-wide PDA that signs CPIs into the access mint and distribution programs
+    pub const AUTHORITY_SEED: &'static [u8] = b"escrow_authority";
+    
+    /// How long a buyer has to pay for an escrow after initializing it (1 day)
+    pub const CHECKOUT_TTL_SECS: i64 = 24 * 60 * 60;
+    
+    /// Whether the checkout window of this escrow has passed
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+    
+    /// Validate that the escrow still matches the creator's listing terms and the sale is open
+    pub fn validate_listing(&self, listing_key: Pubkey, listing: &Listing) -> Result<()> {
+        require!(listing_key == self.listing, EscrowError::InvalidListing);
+        listing.validate_sale_open(Clock::get()?.unix_timestamp)?;
+        require!(listing.price == self.price, EscrowError::PriceMismatch);
+        require!(
+            listing.payment_token_mint == self.payment_token_mint,
+            EscrowError::InvalidPaymentMint
+        );
+        
+        Ok(())
+    }
 }
 
 /// Escrow status enum
@@ -53,8 +97,14 @@ impl EscrowState {
 pub enum EscrowStatus {
     /// Escrow initialized but payment not yet received
     Initialized,
+    /// Payment held in the escrow vault, waiting to be settled
+    Funded,
+    /// Access minted; payment held in the distribution vault until the refund window closes
+    Settled,
     /// Payment received and access minted
     Completed,
     /// Escrow cancelled and refunded
     Cancelled,
+    /// Settled purchase refunded inside its refund window and access revoked
+    Refunded,
 }
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Listing Account - creator-owned sale terms for a piece of content
+#[account]
+pub struct Listing {
+    /// The creator's public key
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price in lamports or SPL token amount
+    pub price: u64,
+    
+    /// Accepted payment token mint (None = SOL, Some = SPL token)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Access mint state (access-mint program) used to issue access tokens
+    pub access_mint_state: Pubkey,
+    
+    /// Split state (distribution program) that receives the payment
+    pub split_state: Pubkey,
+    
+    /// Whether the listing currently accepts purchases
+    pub is_active: bool,
+    
+    /// Whether the platform admin has paused this listing (only the admin can lift it)
+    pub paused_by_admin: bool,
+    
+    /// Whether funded escrows must be settled by the creator (approval-based sales)
+    pub requires_approval: bool,
+    
+    /// Seconds after settlement during which the buyer can request a refund (0 = no refunds)
+    pub refund_window_secs: i64,
+    
+    /// Timestamp from which purchases are accepted (0 = immediately)
+    pub sale_start_ts: i64,
+    
+    /// Timestamp after which purchases are rejected (0 = no end)
+    pub sale_end_ts: i64,
+    
+    /// Seed used for PDA derivation
+    pub seed: u64,
+    
+    /// Timestamp when created
+    pub created_ts: i64,
+    
+    /// Timestamp of the last update
+    pub updated_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Listing {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u64 (8) 
+    /// + Option<Pubkey> (1 + 32) + Pubkey (32) + Pubkey (32) + bool (1) + bool (1) + bool (1) 
+    /// + i64 (8) + i64 (8) + i64 (8) + u64 (8) + i64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 33 + 32 + 32 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+    
+    /// Longest refund window a creator can offer (90 days)
+    pub const MAX_REFUND_WINDOW_SECS: i64 = 90 * 24 * 60 * 60;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = b"listing";
+    
+    /// Whether the sale window has closed (scheduled end reached or ended early)
+    pub fn sale_ended(&self, now: i64) -> bool {
+        self.sale_end_ts != 0 && now >= self.sale_end_ts
+    }
+    
+    /// Validate that the listing accepts purchases right now
+    pub fn validate_sale_open(&self, now: i64) -> Result<()> {
+        require!(!self.paused_by_admin, EscrowError::ListingPaused);
+        require!(self.is_active, EscrowError::ListingInactive);
+        require!(now >= self.sale_start_ts, EscrowError::SaleNotStarted);
+        require!(!self.sale_ended(now), EscrowError::SaleEnded);
+        
+        Ok(())
+    }
+    
+    /// Apply purchase terms from `create_listing` or `update_listing`
+    pub fn apply_config(&mut self, config: &ListingConfig) {
+        self.requires_approval = config.requires_approval;
+        self.refund_window_secs = config.refund_window_secs;
+        self.sale_start_ts = config.sale_start_ts;
+        self.sale_end_ts = config.sale_end_ts;
+    }
+}
+
+/// Purchase terms chosen by the creator when creating or updating a listing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ListingConfig {
+    /// Whether funded escrows must be settled by the creator (approval-based sales)
+    pub requires_approval: bool,
+    /// Seconds after settlement during which the buyer can request a refund (0 = no refunds)
+    pub refund_window_secs: i64,
+    /// Timestamp from which purchases are accepted (0 = immediately)
+    pub sale_start_ts: i64,
+    /// Timestamp after which purchases are rejected (0 = no end)
+    pub sale_end_ts: i64,
+}
+
+impl ListingConfig {
+    /// Check the refund window and sale window
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (0..=Listing::MAX_REFUND_WINDOW_SECS).contains(&self.refund_window_secs),
+            EscrowError::InvalidRefundWindow
+        );
+        require!(
+            self.sale_start_ts >= 0
+                && (self.sale_end_ts == 0 || self.sale_end_ts > self.sale_start_ts),
+            EscrowError::InvalidSaleWindow
+        );
+        
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
 pub mod escrow;
+pub mod listing;
 
 pub use escrow::*;
+pub use listing::*;
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+    transfer_hook::TransferHook,
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{Account as SplAccount, Mint as SplMint};
+use anchor_spl::token_interface::{
+    self, CloseAccount, HarvestWithheldTokensToMint, Mint, TokenAccount, TransferChecked,
+};
+use crate::errors::*;
+
+/// Check that `token_program` is the legacy token program or Token-2022
+pub(crate) fn validate_token_program(token_program: &AccountInfo) -> Result<()> {
+    require!(
+        token_program.key() == anchor_spl::token::ID
+            || token_program.key() == anchor_spl::token_2022::ID,
+        EscrowError::InvalidTokenProgram
+    );
+    
+    Ok(())
+}
+
+/// Deserialize a token account owned by `token_program` (extensions allowed)
+pub(crate) fn read_token_account(account: &AccountInfo, token_program: &AccountInfo) -> Result<TokenAccount> {
+    require!(
+        *account.owner == token_program.key(),
+        EscrowError::InvalidVault
+    );
+    
+    let data = account.try_borrow_data()?;
+    TokenAccount::try_deserialize(&mut &data[..]).map_err(|_| EscrowError::InvalidVault.into())
+}
+
+/// Check that `account` is the associated token account of `owner` for `mint`
+/// Escrow vaults only ever hold tokens there, so refunds and closes cannot be pointed elsewhere
+pub(crate) fn validate_associated_token_account(
+    account: &AccountInfo,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &AccountInfo,
+) -> Result<()> {
+    require!(
+        account.key() == get_associated_token_address_with_program_id(owner, mint, &token_program.key()),
+        EscrowError::InvalidVault
+    );
+    
+    Ok(())
+}
+
+/// Deserialize a mint owned by `token_program` (extensions allowed)
+pub(crate) fn read_mint(mint: &AccountInfo, token_program: &AccountInfo) -> Result<Mint> {
+    require!(
+        *mint.owner == token_program.key(),
+        EscrowError::InvalidPaymentMint
+    );
+    
+    let data = mint.try_borrow_data()?;
+    Mint::try_deserialize(&mut &data[..]).map_err(|_| EscrowError::InvalidPaymentMint.into())
+}
+
+/// Reject mints whose transfers need extra accounts (transfer hooks)
+pub(crate) fn validate_payment_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    if let Ok(hook) = state.get_extension::<TransferHook>() {
+        require!(
+            Option::<Pubkey>::from(hook.program_id).is_none(),
+            EscrowError::UnsupportedMintExtension
+        );
+    }
+    
+    Ok(())
+}
+
+/// Move `amount` tokens with `transfer_checked`, using the mint's decimals
+pub(crate) fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let decimals = read_mint(mint, token_program)?.decimals;
+    
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// Move transfer fees withheld in `account` to the mint so the account can be closed
+/// No-op for legacy mints and mints without the transfer fee extension
+pub(crate) fn harvest_withheld_fees<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+) -> Result<()> {
+    if token_program.key() != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    
+    let withheld = {
+        let data = account.try_borrow_data()?;
+        let state = StateWithExtensions::<SplAccount>::unpack(&data)?;
+        state
+            .get_extension::<TransferFeeAmount>()
+            .map(|fee| u64::from(fee.withheld_amount))
+            .unwrap_or(0)
+    };
+    let has_fee_config = {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+        state.get_extension::<TransferFeeConfig>().is_ok()
+    };
+    
+    if withheld > 0 && has_fee_config {
+        token_interface::harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token_program.clone(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            vec![account.clone()],
+        )?;
+    }
+    
+    Ok(())
+}
+
+/// Accounts used to close an escrow's token vault to the buyer
+pub(crate) struct TokenVaultClose<'info> {
+    pub buyer: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub payment_token_mint: AccountInfo<'info>,
+    pub buyer_token_account: AccountInfo<'info>,
+    pub vault_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> TokenVaultClose<'info> {
+    /// Return any tokens left in the vault to the buyer, then close it so its rent returns to the buyer too
+    /// Only tokens sent outside a purchase can be left, and returning them means they cannot block the close
+    /// A vault token account that was never created is skipped
+    pub fn execute(self, payment_token_mint: Pubkey, buyer: Pubkey, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        validate_token_program(&self.token_program)?;
+        require!(
+            self.payment_token_mint.key() == payment_token_mint,
+            EscrowError::InvalidPaymentMint
+        );
+        validate_associated_token_account(
+            &self.vault_token_account,
+            &self.vault.key(),
+            &payment_token_mint,
+            &self.token_program,
+        )?;
+        
+        if self.vault_token_account.data_is_empty() {
+            return Ok(());
+        }
+        
+        let vault_token = read_token_account(&self.vault_token_account, &self.token_program)?;
+        if vault_token.amount > 0 {
+            let buyer_token = read_token_account(&self.buyer_token_account, &self.token_program)
+                .map_err(|_| EscrowError::InvalidBuyer)?;
+            require!(
+                buyer_token.owner == buyer && buyer_token.mint == payment_token_mint,
+                EscrowError::InvalidBuyer
+            );
+            
+            transfer_tokens(
+                &self.token_program,
+                &self.vault_token_account,
+                &self.payment_token_mint,
+                &self.buyer_token_account,
+                &self.vault,
+                vault_token.amount,
+                signer_seeds,
+            )?;
+        }
+        
+        // Token-2022 accounts holding withheld transfer fees cannot be closed
+        harvest_withheld_fees(&self.token_program, &self.payment_token_mint, &self.vault_token_account)?;
+        
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program,
+            CloseAccount {
+                account: self.vault_token_account,
+                destination: self.buyer,
+                authority: self.vault,
+            },
+            signer_seeds,
+        ))?;
+        
+        msg!("Closed vault token account");
+        
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PaymentEscrow } from "../target/types/payment_escrow";
+import { AccessMint } from "../../access-mint/target/types/access_mint";
+import { Distribution } from "../../distribution/target/types/distribution";
 import {
   PublicKey,
   SystemProgram,
   Keypair,
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("Payment Escrow Program", () => {
@@ -14,12 +17,67 @@ describe("Payment Escrow Program", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.PaymentEscrow as Program<PaymentEscrow>;
+  const accessMintProgram = new Program<AccessMint>(
+    require("../../access-mint/target/idl/access_mint.json"),
+    provider
+  );
+  const distributionProgram = new Program<Distribution>(
+    require("../../distribution/target/idl/distribution.json"),
+    provider
+  );
 
   let buyer: Keypair;
   let creator: Keypair;
+  let listingPda: PublicKey;
 
   const contentId = Array.from({ length: 32 }, (_, i) => i + 1);
+
+  const accessTokenMetadata = {
+    name: "Premium Course Access",
+    symbol: "ACCESS",
+    uri: "https://example.com/access/1.json",
+  };
+
+  // Metaplex token metadata program, loaded from tests/fixtures (see Anchor.toml)
+  const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+  const findMetadataPda = (mintKey: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mintKey.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
   const price = new anchor.BN(1 * LAMPORTS_PER_SOL);
+  const refundWindowSecs = new anchor.BN(7 * 24 * 60 * 60);
+  const listingSeed = new anchor.BN(1);
+
+  // Distribution platform config: split fee policy, treasury and the global pause flag
+  const [platformConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    distributionProgram.programId
+  );
+  // Events are emitted with `emit_cpi!`: each is a self-invoked inner instruction whose data
+  // is the 8-byte event instruction tag followed by the encoded event
+  const fetchCpiEvents = async (signature: string) => {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const accountKeys = tx.transaction.message.getAccountKeys();
+
+    return (tx.meta?.innerInstructions ?? [])
+      .flatMap((inner) => inner.instructions)
+      .filter((ix) => accountKeys.get(ix.programIdIndex)?.equals(program.programId))
+      .map((ix) => {
+        const data = Buffer.from(anchor.utils.bytes.bs58.decode(ix.data)).subarray(8);
+        return program.coder.events.decode(data.toString("base64"));
+      })
+      .filter((event) => event !== null);
+  };
+  const listingConfig = {
+    requiresApproval: false,
+    refundWindowSecs,
+    saleStartTs: new anchor.BN(0),
+    saleEndTs: new anchor.BN(0),
+  };
   
   // Helper to generate unique seed
   const getUniqueSeed = () => new anchor.BN(Date.now() + Math.floor(Math.random() * 1000));
@@ -35,6 +93,112 @@ describe("Payment Escrow Program", () => {
     );
     await provider.connection.confirmTransaction(airdropSig);
 
+    // Creator registers the access mint and split the listing points at
+    const mint = Keypair.generate();
+    const [accessMintStatePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access_mint_state"),
+        creator.publicKey.toBuffer(),
+        Buffer.from(contentId),
+        listingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      accessMintProgram.programId
+    );
+    const [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access_mint_authority"),
+        creator.publicKey.toBuffer(),
+        Buffer.from(contentId),
+        listingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      accessMintProgram.programId
+    );
+    const [escrowAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_authority")],
+      program.programId
+    );
+    await accessMintProgram.methods
+      .initializeMint(contentId, listingSeed, escrowAuthorityPda, accessTokenMetadata, { transferMode: { transferable: {} }, accessDurationSecs: new anchor.BN(0), maxSupply: null })
+      .accountsPartial({
+        creator: creator.publicKey,
+        accessMintState: accessMintStatePda,
+        mint: mint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        metadata: findMetadataPda(mint.publicKey),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([creator, mint])
+      .rpc();
+
+    const [splitPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("split"),
+        creator.publicKey.toBuffer(),
+        Buffer.from(contentId),
+        listingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      distributionProgram.programId
+    );
+
+    // Splits read their fee bounds and treasury from the distribution platform config
+    if (!(await provider.connection.getAccountInfo(platformConfigPda))) {
+      const [programDataPda] = PublicKey.findProgramAddressSync(
+        [distributionProgram.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+      await distributionProgram.methods
+        .initializePlatformConfig({
+          treasury: Keypair.generate().publicKey,
+          minPlatformFeeBps: 100,
+          maxPlatformFeeBps: 1000,
+          maxCollaborators: 10,
+        })
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          distributionProgram: distributionProgram.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    await distributionProgram.methods
+      .initializeSplit(contentId, 250, [], listingSeed, escrowAuthorityPda, { creator: {} })
+      .accountsPartial({
+        creator: creator.publicKey,
+        platformConfig: platformConfigPda,
+        paymentTokenMint: SystemProgram.programId,
+        splitState: splitPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([creator])
+      .rpc();
+
+    [listingPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("listing"),
+        creator.publicKey.toBuffer(),
+        Buffer.from(contentId),
+        listingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    await program.methods
+      .createListing(contentId, price, null, listingConfig, listingSeed)
+      .accountsPartial({
+        creator: creator.publicKey,
+        accessMintState: accessMintStatePda,
+        splitState: splitPda,
+        listing: listingPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([creator])
+      .rpc();
+
     console.log("Test accounts initialized");
     console.log("Program ID:", program.programId.toString());
   });
@@ -57,10 +221,11 @@ describe("Payment Escrow Program", () => {
       );
 
       const tx = await program.methods
-        .initializeEscrow(contentId, price, null, seed)
+        .initializeEscrow(seed)
         .accountsPartial({
           buyer: buyer.publicKey,
-          creator: creator.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
           escrowState: escrowPda,
           systemProgram: SystemProgram.programId,
         })
@@ -98,10 +263,11 @@ describe("Payment Escrow Program", () => {
       );
 
       await program.methods
-        .initializeEscrow(contentId, price, null, seed2)
+        .initializeEscrow(seed2)
         .accountsPartial({
           buyer: buyer.publicKey,
-          creator: creator.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
           escrowState: escrowPda2,
           systemProgram: SystemProgram.programId,
         })
@@ -124,6 +290,246 @@ describe("Payment Escrow Program", () => {
     });
   });
 
+  describe("Listing", () => {
+    it("Should store creator-defined sale terms", async () => {
+      const listing = await program.account.listing.fetch(listingPda);
+
+      expect(listing.creator.toString()).to.equal(creator.publicKey.toString());
+      expect(listing.price.toString()).to.equal(price.toString());
+      expect(listing.paymentTokenMint).to.be.null;
+      expect(listing.isActive).to.be.true;
+      expect(listing.refundWindowSecs.toString()).to.equal(refundWindowSecs.toString());
+
+      console.log("Listing price:", listing.price.toNumber() / LAMPORTS_PER_SOL, "SOL");
+    });
+
+    it("Should reject escrows against an inactive listing", async () => {
+      await program.methods
+        .pauseSale()
+        .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+        .signers([creator])
+        .rpc();
+
+      const seed = getUniqueSeed();
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeEscrow(seed)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            listing: listingPda,
+            platformConfig: platformConfigPda,
+            escrowState: escrowPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown ListingInactive error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("ListingInactive");
+        console.log("Correctly rejected escrow for inactive listing");
+      } finally {
+        await program.methods
+          .resumeSale()
+          .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+          .signers([creator])
+          .rpc();
+      }
+    });
+
+    it("Should reject a negative refund window", async () => {
+      try {
+        await program.methods
+          .updateListing(price, { ...listingConfig, refundWindowSecs: new anchor.BN(-1) })
+          .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have thrown InvalidRefundWindow error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidRefundWindow");
+        console.log("Correctly rejected negative refund window");
+      }
+    });
+
+    it("Should only let the creator update the listing", async () => {
+      try {
+        await program.methods
+          .updateListing(new anchor.BN(1), listingConfig)
+          .accountsPartial({ creator: buyer.publicKey, listing: listingPda })
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+        console.log("Correctly rejected listing update from non-creator");
+      }
+    });
+  });
+
+  describe("Sale Windows", () => {
+    const updateListingConfig = (config: typeof listingConfig) =>
+      program.methods
+        .updateListing(price, config)
+        .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+        .signers([creator])
+        .rpc();
+
+    const expectEscrowRejected = async (errorName: string) => {
+      const seed = getUniqueSeed();
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeEscrow(seed)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            listing: listingPda,
+            platformConfig: platformConfigPda,
+            escrowState: escrowPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail(`Should have thrown ${errorName} error`);
+      } catch (error: any) {
+        expect(error.toString()).to.include(errorName);
+      }
+    };
+
+    afterEach(async () => {
+      // Reopen the listing for the suites that follow
+      await updateListingConfig(listingConfig);
+    });
+
+    it("Should reject purchases before the sale starts", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await updateListingConfig({ ...listingConfig, saleStartTs: new anchor.BN(now + 3600) });
+
+      await expectEscrowRejected("SaleNotStarted");
+      console.log("Correctly rejected escrow before the sale started");
+    });
+
+    it("Should reject purchases after the sale ends", async () => {
+      await updateListingConfig({ ...listingConfig, saleEndTs: new anchor.BN(1) });
+
+      await expectEscrowRejected("SaleEnded");
+      console.log("Correctly rejected escrow after the sale ended");
+    });
+
+    it("Should reject a sale window that ends before it starts", async () => {
+      try {
+        await updateListingConfig({
+          ...listingConfig,
+          saleStartTs: new anchor.BN(2000),
+          saleEndTs: new anchor.BN(1000),
+        });
+
+        expect.fail("Should have thrown InvalidSaleWindow error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidSaleWindow");
+        console.log("Correctly rejected inverted sale window");
+      }
+    });
+
+    it("Should let the creator end a sale early and refuse to resume it", async () => {
+      await program.methods
+        .endSale()
+        .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+        .signers([creator])
+        .rpc();
+
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.saleEndTs.toNumber()).to.be.greaterThan(0);
+
+      await expectEscrowRejected("SaleEnded");
+
+      try {
+        await program.methods
+          .resumeSale()
+          .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have thrown SaleEnded error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("SaleEnded");
+        console.log("Correctly refused to resume an ended sale");
+      }
+    });
+
+    it("Should let the platform admin pause a listing", async () => {
+      await program.methods
+        .setListingPaused(true)
+        .accountsPartial({ admin: buyer.publicKey, platformConfig: platformConfigPda, listing: listingPda })
+        .rpc();
+
+      try {
+        await expectEscrowRejected("ListingPaused");
+
+        // The creator cannot lift an admin pause
+        await program.methods
+          .resumeSale()
+          .accountsPartial({ creator: creator.publicKey, listing: listingPda })
+          .signers([creator])
+          .rpc();
+        await expectEscrowRejected("ListingPaused");
+        console.log("Correctly rejected escrow for a listing paused by the admin");
+      } finally {
+        await program.methods
+          .setListingPaused(false)
+          .accountsPartial({ admin: buyer.publicKey, platformConfig: platformConfigPda, listing: listingPda })
+          .rpc();
+      }
+    });
+
+    it("Should only let the platform admin pause a listing", async () => {
+      try {
+        await program.methods
+          .setListingPaused(true)
+          .accountsPartial({ admin: creator.publicKey, platformConfig: platformConfigPda, listing: listingPda })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+        console.log("Correctly rejected listing pause from non-admin");
+      }
+    });
+
+    it("Should only let the creator pause the sale", async () => {
+      try {
+        await program.methods
+          .pauseSale()
+          .accountsPartial({ creator: buyer.publicKey, listing: listingPda })
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+        console.log("Correctly rejected pause from non-creator");
+      }
+    });
+  });
+
   describe("Cancel Escrow", () => {
     it("Should cancel escrow before payment", async () => {
       const seed3 = getUniqueSeed();
@@ -145,10 +551,11 @@ describe("Payment Escrow Program", () => {
 
       // Initialize escrow
       await program.methods
-        .initializeEscrow(contentId, price, null, seed3)
+        .initializeEscrow(seed3)
         .accountsPartial({
           buyer: buyer.publicKey,
-          creator: creator.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
           escrowState: escrowPda3,
           systemProgram: SystemProgram.programId,
         })
@@ -161,10 +568,12 @@ describe("Payment Escrow Program", () => {
       const tx = await program.methods
         .cancelEscrow()
         .accountsPartial({
+          authority: buyer.publicKey,
           buyer: buyer.publicKey,
           escrowState: escrowPda3,
           vault: vaultPda,
           buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
           vaultTokenAccount: vaultPda,         // Placeholder for SOL
           tokenProgram: SystemProgram.programId,
           systemProgram: SystemProgram.programId,
@@ -183,6 +592,281 @@ describe("Payment Escrow Program", () => {
         console.log("Escrow account closed successfully");
       }
     });
+
+    it("Should refund SOL held in the vault and close it on cancel", async () => {
+      const seed5 = getUniqueSeed();
+
+      const [escrowPda5] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed5.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), escrowPda5.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeEscrow(seed5)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
+          escrowState: escrowPda5,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // Fund the escrow vault
+      const fundAmount = LAMPORTS_PER_SOL / 2;
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: buyer.publicKey,
+            toPubkey: vaultPda,
+            lamports: fundAmount,
+          })
+        )
+      );
+      expect(await provider.connection.getBalance(vaultPda)).to.equal(fundAmount);
+
+      const buyerBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      await program.methods
+        .cancelEscrow()
+        .accountsPartial({
+          authority: buyer.publicKey,
+          buyer: buyer.publicKey,
+          escrowState: escrowPda5,
+          vault: vaultPda,
+          buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
+          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          tokenProgram: SystemProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // Vault is drained (and therefore closed), buyer gets funds plus escrow rent back
+      const vaultInfo = await provider.connection.getAccountInfo(vaultPda);
+      expect(vaultInfo).to.be.null;
+
+      const buyerAfter = await provider.connection.getBalance(buyer.publicKey);
+      expect(buyerAfter - buyerBefore).to.be.greaterThan(fundAmount - 10_000);
+
+      console.log("Refunded", fundAmount / LAMPORTS_PER_SOL, "SOL and closed vault");
+    });
+  });
+
+  describe("Deposit and Refund", () => {
+    const initializeEscrow = async () => {
+      const seed = getUniqueSeed();
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), escrowPda.toBuffer()],
+        program.programId
+      );
+
+      const initializeTx = await program.methods
+        .initializeEscrow(seed)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
+          escrowState: escrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc({ commitment: "confirmed" });
+
+      return { escrowPda, vaultPda, initializeTx };
+    };
+
+    const deposit = async (escrowPda: PublicKey, vaultPda: PublicKey) => {
+      const listing = await program.account.listing.fetch(listingPda);
+
+      return program.methods
+        .deposit(price)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          escrowState: escrowPda,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
+          accessMintState: listing.accessMintState,
+          vault: vaultPda,
+          buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
+          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          tokenProgram: SystemProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc({ commitment: "confirmed" });
+    };
+
+    const openEscrow = async () => {
+      const { escrowPda, vaultPda, initializeTx } = await initializeEscrow();
+      const depositTx = await deposit(escrowPda, vaultPda);
+
+      return { escrowPda, vaultPda, initializeTx, depositTx };
+    };
+
+    it("Should reject deposits while the platform or the listing is paused", async () => {
+      const { escrowPda, vaultPda } = await initializeEscrow();
+
+      const expectDepositRejected = async (errorName: string) => {
+        try {
+          await deposit(escrowPda, vaultPda);
+          expect.fail(`Should have thrown ${errorName} error`);
+        } catch (error: any) {
+          expect(error.toString()).to.include(errorName);
+        }
+      };
+
+      const setPlatformPaused = (paused: boolean) =>
+        distributionProgram.methods
+          .setPlatformPaused(paused)
+          .accountsPartial({ admin: buyer.publicKey, platformConfig: platformConfigPda })
+          .rpc();
+      const setListingPaused = (paused: boolean) =>
+        program.methods
+          .setListingPaused(paused)
+          .accountsPartial({ admin: buyer.publicKey, platformConfig: platformConfigPda, listing: listingPda })
+          .rpc();
+
+      await setPlatformPaused(true);
+      try {
+        await expectDepositRejected("PlatformPaused");
+      } finally {
+        await setPlatformPaused(false);
+      }
+
+      await setListingPaused(true);
+      try {
+        await expectDepositRejected("ListingPaused");
+      } finally {
+        await setListingPaused(false);
+      }
+
+      // The same escrow takes the payment once both pauses are lifted
+      await deposit(escrowPda, vaultPda);
+      const escrowState = await program.account.escrowState.fetch(escrowPda);
+      expect(escrowState.status).to.deep.equal({ funded: {} });
+
+      console.log("Correctly rejected deposits while paused");
+    });
+
+    it("Should hold deposited funds in the vault", async () => {
+      const { escrowPda, vaultPda } = await openEscrow();
+
+      const escrowState = await program.account.escrowState.fetch(escrowPda);
+      expect(escrowState.status).to.deep.equal({ funded: {} });
+      expect(escrowState.paymentAmount.toString()).to.equal(price.toString());
+      expect(await provider.connection.getBalance(vaultPda)).to.equal(price.toNumber());
+
+      console.log("Escrow funded with", price.toNumber() / LAMPORTS_PER_SOL, "SOL");
+    });
+
+    it("Should refund the buyer when a funded escrow is cancelled", async () => {
+      const { escrowPda, vaultPda } = await openEscrow();
+      const buyerBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      await program.methods
+        .cancelEscrow()
+        .accountsPartial({
+          authority: buyer.publicKey,
+          buyer: buyer.publicKey,
+          escrowState: escrowPda,
+          vault: vaultPda,
+          buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
+          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          tokenProgram: SystemProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const buyerAfter = await provider.connection.getBalance(buyer.publicKey);
+      expect(buyerAfter - buyerBefore).to.be.greaterThan(price.toNumber() - 10_000);
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null;
+
+      console.log("Funded escrow cancelled and refunded");
+    });
+
+    it("Should let the creator refund a funded escrow", async () => {
+      const { escrowPda, vaultPda } = await openEscrow();
+      const buyerBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      await program.methods
+        .cancelEscrow()
+        .accountsPartial({
+          authority: creator.publicKey,
+          buyer: buyer.publicKey,
+          escrowState: escrowPda,
+          vault: vaultPda,
+          buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
+          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          tokenProgram: SystemProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const buyerAfter = await provider.connection.getBalance(buyer.publicKey);
+      expect(buyerAfter - buyerBefore).to.be.greaterThan(price.toNumber());
+
+      console.log("Creator refunded funded escrow");
+    });
+
+    it("Should emit an event for each escrow transition", async () => {
+      const { escrowPda, vaultPda, initializeTx, depositTx } = await openEscrow();
+
+      const [initialized] = await fetchCpiEvents(initializeTx);
+      expect(initialized.name).to.equal("escrowInitialized");
+      expect(initialized.data.escrow.toString()).to.equal(escrowPda.toString());
+      expect(initialized.data.listing.toString()).to.equal(listingPda.toString());
+      expect(initialized.data.price.toString()).to.equal(price.toString());
+
+      const [received] = await fetchCpiEvents(depositTx);
+      expect(received.name).to.equal("paymentReceived");
+      expect(received.data.buyer.toString()).to.equal(buyer.publicKey.toString());
+      expect(received.data.amount.toString()).to.equal(price.toString());
+      expect(received.data.renewal).to.be.false;
+
+      const cancelTx = await program.methods
+        .cancelEscrow()
+        .accountsPartial({
+          authority: buyer.publicKey,
+          buyer: buyer.publicKey,
+          escrowState: escrowPda,
+          vault: vaultPda,
+          buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
+          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          tokenProgram: SystemProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc({ commitment: "confirmed" });
+
+      const [cancelled] = await fetchCpiEvents(cancelTx);
+      expect(cancelled.name).to.equal("escrowCancelled");
+      expect(cancelled.data.cancelledBy.toString()).to.equal(buyer.publicKey.toString());
+      expect(cancelled.data.refundedAmount.toString()).to.equal(price.toString());
+
+      console.log("Escrow events: initialized, payment received, cancelled");
+    });
   });
 
   describe("Escrow Lifecycle", () => {
@@ -201,10 +885,11 @@ describe("Payment Escrow Program", () => {
 
       // Initialize
       await program.methods
-        .initializeEscrow(contentId, price, null, seed4)
+        .initializeEscrow(seed4)
         .accountsPartial({
           buyer: buyer.publicKey,
-          creator: creator.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
           escrowState: escrowPda,
           systemProgram: SystemProgram.programId,
         })
@@ -219,6 +904,9 @@ describe("Payment Escrow Program", () => {
       expect(escrowState.price.toNumber()).to.be.greaterThan(0);
       expect(escrowState.paymentAmount.toNumber()).to.equal(0);
       expect(escrowState.accessMintAddress).to.be.null;
+      expect(escrowState.refundWindowSecs.toString()).to.equal(refundWindowSecs.toString());
+      expect(escrowState.settleAfter.toNumber()).to.equal(0);
+      expect(escrowState.expiresAt.toNumber() - escrowState.createdTs.toNumber()).to.equal(24 * 60 * 60);
 
       console.log("Escrow lifecycle tracked");
       console.log("Buyer:", escrowState.buyer.toString());
@@ -227,4 +915,98 @@ describe("Payment Escrow Program", () => {
       console.log("Payment amount:", escrowState.paymentAmount.toNumber());
     });
   });
+
+  describe("Escrow Cleanup", () => {
+    let escrowPda: PublicKey;
+    let vaultPda: PublicKey;
+
+    const closeCompletedAccounts = (rentRecipient: PublicKey) => ({
+      buyer: rentRecipient,
+      escrowState: escrowPda,
+      vault: vaultPda,
+      paymentTokenMint: SystemProgram.programId,
+      buyerTokenAccount: rentRecipient, // Placeholder for SOL
+      vaultTokenAccount: vaultPda,      // Placeholder for SOL
+      tokenProgram: SystemProgram.programId,
+      systemProgram: SystemProgram.programId,
+    });
+
+    before(async () => {
+      const seed = getUniqueSeed();
+      [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), escrowPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeEscrow(seed)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          listing: listingPda,
+          platformConfig: platformConfigPda,
+          escrowState: escrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should not close an unfunded escrow before it expires", async () => {
+      try {
+        await program.methods
+          .closeExpiredEscrow()
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            escrowState: escrowPda,
+            vault: vaultPda,
+            paymentTokenMint: SystemProgram.programId,
+            buyerTokenAccount: buyer.publicKey, // Placeholder for SOL
+            vaultTokenAccount: vaultPda,        // Placeholder for SOL
+            tokenProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown EscrowNotExpired error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("EscrowNotExpired");
+        console.log("Correctly kept an unexpired escrow open");
+      }
+    });
+
+    it("Should not close an escrow that has not completed", async () => {
+      try {
+        await program.methods
+          .closeCompletedEscrow()
+          .accountsPartial(closeCompletedAccounts(buyer.publicKey))
+          .rpc();
+
+        expect.fail("Should have thrown InvalidEscrowStatus error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidEscrowStatus");
+        console.log("Correctly kept an open escrow from being closed");
+      }
+    });
+
+    it("Should only return escrow rent to the buyer", async () => {
+      try {
+        await program.methods
+          .closeCompletedEscrow()
+          .accountsPartial(closeCompletedAccounts(creator.publicKey))
+          .rpc();
+
+        expect.fail("Should have thrown InvalidBuyer error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidBuyer");
+        console.log("Correctly rejected a rent recipient other than the buyer");
+      }
+    });
+  });
 });
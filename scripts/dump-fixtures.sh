@@ -0,0 +1,20 @@
+#!/usr/bin/env sh
+# Dump the Metaplex Token Metadata program from mainnet into the test fixtures.
+#
+# `anchor test` in access-mint/ and payment-escrow/ loads it into the local validator
+# (see [[test.genesis]] in their Anchor.toml), and the LiteSVM integration tests load
+# it from access-mint/tests/fixtures. Program binaries are gitignored, so run this once
+# after cloning:
+#
+#     scripts/dump-fixtures.sh
+set -eu
+
+METADATA_PROGRAM_ID=metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s
+ROOT=$(cd "$(dirname "$0")/.." && pwd)
+FIXTURE=tests/fixtures/mpl_token_metadata.so
+
+mkdir -p "$ROOT/access-mint/tests/fixtures" "$ROOT/payment-escrow/tests/fixtures"
+solana program dump --url mainnet-beta "$METADATA_PROGRAM_ID" "$ROOT/access-mint/$FIXTURE"
+cp "$ROOT/access-mint/$FIXTURE" "$ROOT/payment-escrow/$FIXTURE"
+
+echo "Wrote access-mint/$FIXTURE and payment-escrow/$FIXTURE"
@@ -0,0 +1,86 @@
+use anchor_lang::{AccountDeserialize, Result};
+
+pub use access_mint::state::{AccessMintState, AccessRecord, TransferMode};
+pub use distribution::state::{Collaborator, DustPolicy, PlatformConfig, SplitState};
+pub use payment_escrow::state::{EscrowState, EscrowStatus, Listing};
+
+/// Decode any program account from its raw data, checking the Anchor discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Decode an escrow state account
+pub fn escrow_state(data: &[u8]) -> Result<EscrowState> {
+    decode(data)
+}
+
+/// Decode a listing account
+pub fn listing(data: &[u8]) -> Result<Listing> {
+    decode(data)
+}
+
+/// Decode a split state account
+pub fn split_state(data: &[u8]) -> Result<SplitState> {
+    decode(data)
+}
+
+/// Decode the platform config account
+pub fn platform_config(data: &[u8]) -> Result<PlatformConfig> {
+    decode(data)
+}
+
+/// Decode an access mint state account
+pub fn access_mint_state(data: &[u8]) -> Result<AccessMintState> {
+    decode(data)
+}
+
+/// Decode an access record account
+pub fn access_record(data: &[u8]) -> Result<AccessRecord> {
+    decode(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::AccountSerialize;
+    
+    fn access_mint() -> AccessMintState {
+        AccessMintState {
+            creator: Pubkey::new_unique(),
+            content_id: [2; 32],
+            mint: Pubkey::new_unique(),
+            mint_authority: Pubkey::new_unique(),
+            minter: Pubkey::new_unique(),
+            seed: 1,
+            total_minted: 3,
+            active_supply: 2,
+            created_ts: 0,
+            transfer_mode: TransferMode::Frozen,
+            access_duration_secs: 0,
+            max_supply: Some(10),
+            bump: 254,
+        }
+    }
+    
+    #[test]
+    fn decodes_serialized_account() {
+        let state = access_mint();
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        
+        let decoded = access_mint_state(&data).unwrap();
+        assert_eq!(decoded.mint, state.mint);
+        assert_eq!(decoded.max_supply, Some(10));
+        assert_eq!(decoded.transfer_mode, TransferMode::Frozen);
+    }
+    
+    #[test]
+    fn rejects_other_account_types() {
+        let mut data = Vec::new();
+        access_mint().try_serialize(&mut data).unwrap();
+        
+        assert!(split_state(&data).is_err());
+        assert!(escrow_state(&data).is_err());
+    }
+}
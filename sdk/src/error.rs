@@ -0,0 +1,222 @@
+use std::fmt;
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::error::InstructionError;
+use access_mint::errors::AccessMintError;
+use distribution::errors::DistributionError;
+use payment_escrow::errors::EscrowError;
+
+/// Custom error raised by one of the three programs
+#[derive(Clone, Copy, Debug)]
+pub enum OwnmarkError {
+    /// Payment escrow program error
+    Escrow(EscrowError),
+    /// Access mint program error
+    AccessMint(AccessMintError),
+    /// Distribution program error
+    Distribution(DistributionError),
+}
+
+impl OwnmarkError {
+    /// Program that defines the error
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            OwnmarkError::Escrow(_) => payment_escrow::ID,
+            OwnmarkError::AccessMint(_) => access_mint::ID,
+            OwnmarkError::Distribution(_) => distribution::ID,
+        }
+    }
+    
+    /// Custom error code as returned on-chain
+    pub fn code(&self) -> u32 {
+        match self {
+            OwnmarkError::Escrow(e) => u32::from(*e),
+            OwnmarkError::AccessMint(e) => u32::from(*e),
+            OwnmarkError::Distribution(e) => u32::from(*e),
+        }
+    }
+    
+    /// Variant name, e.g. `SoldOut`
+    pub fn name(&self) -> String {
+        match self {
+            OwnmarkError::Escrow(e) => e.name(),
+            OwnmarkError::AccessMint(e) => e.name(),
+            OwnmarkError::Distribution(e) => e.name(),
+        }
+    }
+}
+
+impl fmt::Display for OwnmarkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OwnmarkError::Escrow(e) => write!(f, "{}: {}", e.name(), e),
+            OwnmarkError::AccessMint(e) => write!(f, "{}: {}", e.name(), e),
+            OwnmarkError::Distribution(e) => write!(f, "{}: {}", e.name(), e),
+        }
+    }
+}
+
+impl std::error::Error for OwnmarkError {}
+
+/// Decode a custom error `code` returned by `program_id`
+/// Returns `None` for other programs and for Anchor framework errors (codes below 6000)
+pub fn decode_error(program_id: &Pubkey, code: u32) -> Option<OwnmarkError> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)? as usize;
+    
+    if *program_id == payment_escrow::ID {
+        ESCROW_ERRORS.get(index).copied().map(OwnmarkError::Escrow)
+    } else if *program_id == access_mint::ID {
+        ACCESS_MINT_ERRORS.get(index).copied().map(OwnmarkError::AccessMint)
+    } else if *program_id == distribution::ID {
+        DISTRIBUTION_ERRORS.get(index).copied().map(OwnmarkError::Distribution)
+    } else {
+        None
+    }
+}
+
+/// Decode the error of a failed instruction sent to `program_id`
+pub fn decode_instruction_error(program_id: &Pubkey, error: &InstructionError) -> Option<OwnmarkError> {
+    match error {
+        InstructionError::Custom(code) => decode_error(program_id, *code),
+        _ => None,
+    }
+}
+
+/// Declare `$name` as every variant of `$error` in declaration order, so that index + 6000 is the on-chain code
+/// The exhaustive match stops the build if a variant is added to the program but not to the table
+macro_rules! error_table {
+    ($name:ident: $error:ident { $($variant:ident),+ $(,)? }) => {
+        const $name: &[$error] = &[$($error::$variant),+];
+        
+        const _: fn($error) = |e| match e {
+            $($error::$variant)|+ => {}
+        };
+    };
+}
+
+error_table!(ESCROW_ERRORS: EscrowError {
+    InvalidPrice,
+    InvalidPaymentAmount,
+    EscrowAlreadyCompleted,
+    EscrowAlreadyCancelled,
+    InvalidEscrowStatus,
+    InvalidContentId,
+    InvalidBuyer,
+    InvalidCreator,
+    Unauthorized,
+    NumericalOverflow,
+    InvalidSeed,
+    InvalidVault,
+    InsufficientFunds,
+    ListingInactive,
+    InvalidListing,
+    PriceMismatch,
+    InvalidPaymentMint,
+    InvalidAccessMintState,
+    InvalidSplitState,
+    ApprovalRequired,
+    InvalidRefundWindow,
+    RefundWindowOpen,
+    RefundWindowClosed,
+    InvalidTokenProgram,
+    UnsupportedMintExtension,
+    SoldOut,
+    InvalidSaleWindow,
+    SaleNotStarted,
+    SaleEnded,
+    EscrowExpired,
+    EscrowNotExpired,
+    PlatformPaused,
+    ListingPaused,
+});
+
+error_table!(ACCESS_MINT_ERRORS: AccessMintError {
+    InvalidCreator,
+    InvalidContentId,
+    InvalidMint,
+    InvalidBuyer,
+    Unauthorized,
+    InvalidMintAuthority,
+    AlreadyMinted,
+    NumericalOverflow,
+    MetadataTooLong,
+    InvalidAccessDuration,
+    NotRenewable,
+    AccessRevoked,
+    MissingTokenAccount,
+    InvalidMaxSupply,
+    SoldOut,
+    PlatformPaused,
+});
+
+error_table!(DISTRIBUTION_ERRORS: DistributionError {
+    InvalidCreator,
+    InvalidContentId,
+    InvalidPlatformFee,
+    InvalidShareDistribution,
+    TooManyCollaborators,
+    InvalidCollaborator,
+    InsufficientFunds,
+    Unauthorized,
+    NumericalOverflow,
+    InvalidVault,
+    InvalidRecipient,
+    InvalidTokenAccount,
+    SplitLocked,
+    MissingCollaboratorSignature,
+    UnclaimedBalance,
+    NothingToClaim,
+    InvalidPaymentMint,
+    InvalidTokenProgram,
+    UnsupportedMintExtension,
+    VaultNotEmpty,
+    PlatformPaused,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn tables_follow_declaration_order() {
+        for (index, e) in ESCROW_ERRORS.iter().enumerate() {
+            assert_eq!(u32::from(*e), ERROR_CODE_OFFSET + index as u32, "{}", e.name());
+        }
+        for (index, e) in ACCESS_MINT_ERRORS.iter().enumerate() {
+            assert_eq!(u32::from(*e), ERROR_CODE_OFFSET + index as u32, "{}", e.name());
+        }
+        for (index, e) in DISTRIBUTION_ERRORS.iter().enumerate() {
+            assert_eq!(u32::from(*e), ERROR_CODE_OFFSET + index as u32, "{}", e.name());
+        }
+    }
+    
+    #[test]
+    fn same_code_decodes_per_program() {
+        let code = u32::from(EscrowError::Unauthorized);
+        
+        let escrow = decode_error(&payment_escrow::ID, code).unwrap();
+        assert_eq!(escrow.name(), "Unauthorized");
+        assert_eq!(escrow.code(), code);
+        assert_eq!(escrow.program_id(), payment_escrow::ID);
+        
+        // Every program numbers its errors from 6000, so the code alone is ambiguous
+        let distribution = decode_error(&distribution::ID, code).unwrap();
+        assert_eq!(distribution.program_id(), distribution::ID);
+        assert_eq!(distribution.name(), "NumericalOverflow");
+    }
+    
+    #[test]
+    fn ignores_framework_and_unknown_errors() {
+        assert!(decode_error(&payment_escrow::ID, 2006).is_none());
+        assert!(decode_error(&payment_escrow::ID, ERROR_CODE_OFFSET + 1000).is_none());
+        assert!(decode_error(&Pubkey::new_unique(), ERROR_CODE_OFFSET).is_none());
+        assert!(decode_instruction_error(&access_mint::ID, &InstructionError::MissingRequiredSignature).is_none());
+        
+        let paused = decode_instruction_error(
+            &access_mint::ID,
+            &InstructionError::Custom(u32::from(AccessMintError::PlatformPaused)),
+        )
+        .unwrap();
+        assert_eq!(paused.to_string(), format!("PlatformPaused: {}", AccessMintError::PlatformPaused));
+    }
+}
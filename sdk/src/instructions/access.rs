@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, sysvar};
+use anchor_spl::token;
+use access_mint::state::{AccessConfig, AccessTokenMetadata};
+use access_mint::{accounts, instruction};
+use crate::pda;
+use super::build;
+
+/// Create the access token mint for content; `mint` is a fresh keypair that must sign
+/// `minter` is the authority allowed to grant access, normally the escrow authority PDA
+pub fn initialize_mint(
+    creator: Pubkey,
+    mint: Pubkey,
+    content_id: [u8; 32],
+    seed: u64,
+    minter: Pubkey,
+    metadata: AccessTokenMetadata,
+    config: AccessConfig,
+) -> Instruction {
+    build(
+        access_mint::ID,
+        accounts::InitializeMint {
+            creator,
+            access_mint_state: pda::access_mint_state(&creator, &content_id, seed).0,
+            mint,
+            mint_authority: pda::access_mint_authority(&creator, &content_id, seed).0,
+            metadata: pda::metadata(&mint).0,
+            token_metadata_program: anchor_spl::metadata::mpl_token_metadata::ID,
+            token_program: token::ID,
+            system_program: System::id(),
+            rent: sysvar::rent::ID,
+            event_authority: pda::event_authority(&access_mint::ID).0,
+            program: access_mint::ID,
+        },
+        instruction::InitializeMint {
+            content_id,
+            seed,
+            minter,
+            metadata,
+            config,
+        },
+    )
+}
+
+/// Check whether `buyer` holds valid access; simulate it and read the returned bool
+pub fn has_valid_access(buyer: Pubkey, access_mint_state: Pubkey) -> Instruction {
+    build(
+        access_mint::ID,
+        accounts::HasValidAccess {
+            buyer,
+            access_mint_state,
+            access_record: pda::access_record(&access_mint_state, &buyer).0,
+        },
+        instruction::HasValidAccess {},
+    )
+}
@@ -0,0 +1,453 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::{associated_token, token};
+use access_mint::state::AccessMintState;
+use payment_escrow::state::{Listing, ListingConfig};
+use payment_escrow::{accounts, instruction};
+use crate::pda;
+use super::{build, PaymentMode};
+
+/// A buyer's purchase against a listing, with everything needed to derive its accounts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Purchase {
+    /// Buyer paying for access
+    pub buyer: Pubkey,
+    
+    /// Seed of the buyer's escrow for this content
+    pub escrow_seed: u64,
+    
+    /// Listing PDA
+    pub listing: Pubkey,
+    
+    /// Content being purchased
+    pub content_id: [u8; 32],
+    
+    /// Access mint state of the listing
+    pub access_mint_state: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Access mint authority PDA
+    pub access_mint_authority: Pubkey,
+    
+    /// Split that receives the payment
+    pub split_state: Pubkey,
+    
+    /// Currency of the listing
+    pub payment: PaymentMode,
+}
+
+impl Purchase {
+    /// Purchase of a decoded listing and its access mint state
+    /// `token_program` is the payment mint's token program (ignored for SOL listings)
+    pub fn new(
+        buyer: Pubkey,
+        escrow_seed: u64,
+        listing_key: Pubkey,
+        listing: &Listing,
+        access_mint_state: &AccessMintState,
+        token_program: Pubkey,
+    ) -> Self {
+        Self {
+            buyer,
+            escrow_seed,
+            listing: listing_key,
+            content_id: listing.content_id,
+            access_mint_state: listing.access_mint_state,
+            access_mint: access_mint_state.mint,
+            access_mint_authority: access_mint_state.find_mint_authority(&access_mint::ID).0,
+            split_state: listing.split_state,
+            payment: PaymentMode::from_mint(listing.payment_token_mint, token_program),
+        }
+    }
+    
+    /// Escrow state PDA
+    pub fn escrow(&self) -> Pubkey {
+        pda::escrow(&self.buyer, &self.content_id, self.escrow_seed).0
+    }
+    
+    /// Escrow vault PDA
+    pub fn vault(&self) -> Pubkey {
+        pda::escrow_vault(&self.escrow()).0
+    }
+    
+    /// Split vault PDA
+    pub fn distribution_vault(&self) -> Pubkey {
+        pda::split_vault(&self.split_state).0
+    }
+    
+    /// Buyer's access record PDA
+    pub fn access_record(&self) -> Pubkey {
+        pda::access_record(&self.access_mint_state, &self.buyer).0
+    }
+    
+    /// Buyer's access token account (unused by record-only access mints)
+    pub fn buyer_access_token_account(&self) -> Pubkey {
+        pda::token_account(&self.buyer, &self.access_mint, &token::ID)
+    }
+    
+    /// Accounts shared by `buy_and_mint`, `renew` and `settle`
+    fn settlement_accounts(&self, payer: Pubkey) -> accounts::Settle {
+        let vault = self.vault();
+        let distribution_vault = self.distribution_vault();
+        
+        accounts::Settle {
+            payer,
+            buyer: self.buyer,
+            escrow_state: self.escrow(),
+            listing: self.listing,
+            platform_config: pda::platform_config().0,
+            vault,
+            vault_token_account: self.payment.token_account(&vault),
+            payment_token_mint: self.payment.mint(),
+            token_program: self.payment.token_program(),
+            access_mint_program: access_mint::ID,
+            escrow_authority: pda::escrow_authority().0,
+            access_mint_state: self.access_mint_state,
+            access_mint: self.access_mint,
+            mint_authority: self.access_mint_authority,
+            buyer_access_token_account: self.buyer_access_token_account(),
+            access_record: self.access_record(),
+            access_mint_event_authority: pda::event_authority(&access_mint::ID).0,
+            access_token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            distribution_program: distribution::ID,
+            split_state: self.split_state,
+            distribution_vault,
+            distribution_vault_token_account: self.payment.token_account(&distribution_vault),
+            distribution_event_authority: pda::event_authority(&distribution::ID).0,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        }
+    }
+    
+    /// Accounts of `buy_and_mint` and `renew`
+    fn buy_and_mint_accounts(&self) -> accounts::BuyAndMint {
+        let settle = self.settlement_accounts(self.buyer);
+        
+        accounts::BuyAndMint {
+            buyer: self.buyer,
+            escrow_state: settle.escrow_state,
+            listing: settle.listing,
+            platform_config: settle.platform_config,
+            vault: settle.vault,
+            payment_token_mint: settle.payment_token_mint,
+            buyer_token_account: self.payment.token_account(&self.buyer),
+            vault_token_account: settle.vault_token_account,
+            token_program: settle.token_program,
+            access_mint_program: settle.access_mint_program,
+            escrow_authority: settle.escrow_authority,
+            access_mint_state: settle.access_mint_state,
+            access_mint: settle.access_mint,
+            mint_authority: settle.mint_authority,
+            buyer_access_token_account: settle.buyer_access_token_account,
+            access_record: settle.access_record,
+            access_mint_event_authority: settle.access_mint_event_authority,
+            access_token_program: settle.access_token_program,
+            associated_token_program: settle.associated_token_program,
+            distribution_program: settle.distribution_program,
+            split_state: settle.split_state,
+            distribution_vault: settle.distribution_vault,
+            distribution_vault_token_account: settle.distribution_vault_token_account,
+            distribution_event_authority: settle.distribution_event_authority,
+            system_program: settle.system_program,
+            event_authority: settle.event_authority,
+            program: settle.program,
+        }
+    }
+}
+
+/// Create a listing fixing the price and currency of content
+#[allow(clippy::too_many_arguments)]
+pub fn create_listing(
+    creator: Pubkey,
+    content_id: [u8; 32],
+    price: u64,
+    payment: PaymentMode,
+    config: ListingConfig,
+    seed: u64,
+    access_mint_state: Pubkey,
+    split_state: Pubkey,
+) -> Instruction {
+    build(
+        payment_escrow::ID,
+        accounts::CreateListing {
+            creator,
+            access_mint_state,
+            split_state,
+            listing: pda::listing(&creator, &content_id, seed).0,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::CreateListing {
+            content_id,
+            price,
+            payment_token_mint: payment.listing_mint(),
+            config,
+            seed,
+        },
+    )
+}
+
+/// Open the buyer's escrow against the listing
+pub fn initialize_escrow(purchase: &Purchase) -> Instruction {
+    build(
+        payment_escrow::ID,
+        accounts::InitializeEscrow {
+            buyer: purchase.buyer,
+            listing: purchase.listing,
+            platform_config: pda::platform_config().0,
+            escrow_state: purchase.escrow(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::InitializeEscrow {
+            seed: purchase.escrow_seed,
+        },
+    )
+}
+
+/// Pay into the escrow vault without settling
+pub fn deposit(purchase: &Purchase, payment_amount: u64) -> Instruction {
+    let vault = purchase.vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::Deposit {
+            buyer: purchase.buyer,
+            escrow_state: purchase.escrow(),
+            listing: purchase.listing,
+            platform_config: pda::platform_config().0,
+            access_mint_state: purchase.access_mint_state,
+            vault,
+            payment_token_mint: purchase.payment.mint(),
+            buyer_token_account: purchase.payment.token_account(&purchase.buyer),
+            vault_token_account: purchase.payment.token_account(&vault),
+            token_program: purchase.payment.token_program(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::Deposit { payment_amount },
+    )
+}
+
+/// Pay, mint access and distribute in one instruction
+pub fn buy_and_mint(purchase: &Purchase, payment_amount: u64) -> Instruction {
+    build(
+        payment_escrow::ID,
+        purchase.buy_and_mint_accounts(),
+        instruction::BuyAndMint { payment_amount },
+    )
+}
+
+/// Pay for another access period on a time-limited access mint
+pub fn renew(purchase: &Purchase, payment_amount: u64) -> Instruction {
+    build(
+        payment_escrow::ID,
+        purchase.buy_and_mint_accounts(),
+        instruction::Renew { payment_amount },
+    )
+}
+
+/// Settle a funded escrow; `payer` is the buyer or the creator
+pub fn settle(payer: Pubkey, purchase: &Purchase) -> Instruction {
+    build(
+        payment_escrow::ID,
+        purchase.settlement_accounts(payer),
+        instruction::Settle {},
+    )
+}
+
+/// Cancel an unsettled escrow and refund the buyer; `authority` is the buyer or the creator
+pub fn cancel_escrow(authority: Pubkey, purchase: &Purchase) -> Instruction {
+    let vault = purchase.vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::CancelEscrow {
+            authority,
+            buyer: purchase.buyer,
+            escrow_state: purchase.escrow(),
+            vault,
+            payment_token_mint: purchase.payment.mint(),
+            buyer_token_account: purchase.payment.token_account(&purchase.buyer),
+            vault_token_account: purchase.payment.token_account(&vault),
+            token_program: purchase.payment.token_program(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::CancelEscrow {},
+    )
+}
+
+/// Refund a settled purchase while its refund window is open (buyer signs)
+pub fn request_refund(purchase: &Purchase) -> Instruction {
+    let distribution_vault = purchase.distribution_vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::RequestRefund {
+            buyer: purchase.buyer,
+            escrow_state: purchase.escrow(),
+            escrow_authority: pda::escrow_authority().0,
+            access_mint_program: access_mint::ID,
+            access_mint_state: purchase.access_mint_state,
+            access_mint: purchase.access_mint,
+            access_mint_authority: purchase.access_mint_authority,
+            buyer_access_token_account: purchase.buyer_access_token_account(),
+            access_record: purchase.access_record(),
+            access_mint_event_authority: pda::event_authority(&access_mint::ID).0,
+            access_token_program: token::ID,
+            distribution_program: distribution::ID,
+            split_state: purchase.split_state,
+            distribution_vault,
+            payment_token_mint: purchase.payment.mint(),
+            distribution_vault_token_account: purchase.payment.token_account(&distribution_vault),
+            buyer_token_account: purchase.payment.token_account(&purchase.buyer),
+            token_program: purchase.payment.token_program(),
+            distribution_event_authority: pda::event_authority(&distribution::ID).0,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::RequestRefund {},
+    )
+}
+
+/// Credit a settled purchase to the split once its refund window has closed (permissionless)
+pub fn release_payout(purchase: &Purchase) -> Instruction {
+    let distribution_vault = purchase.distribution_vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::ReleasePayout {
+            escrow_state: purchase.escrow(),
+            escrow_authority: pda::escrow_authority().0,
+            distribution_program: distribution::ID,
+            split_state: purchase.split_state,
+            platform_config: pda::platform_config().0,
+            distribution_vault,
+            distribution_vault_token_account: purchase.payment.token_account(&distribution_vault),
+            distribution_event_authority: pda::event_authority(&distribution::ID).0,
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::ReleasePayout {},
+    )
+}
+
+/// Close an expired, never-funded escrow and its token vault, returning their rent to the buyer (permissionless)
+pub fn close_expired_escrow(purchase: &Purchase) -> Instruction {
+    let vault = purchase.vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::CloseExpiredEscrow {
+            buyer: purchase.buyer,
+            escrow_state: purchase.escrow(),
+            vault,
+            payment_token_mint: purchase.payment.mint(),
+            buyer_token_account: purchase.payment.token_account(&purchase.buyer),
+            vault_token_account: purchase.payment.token_account(&vault),
+            token_program: purchase.payment.token_program(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::CloseExpiredEscrow {},
+    )
+}
+
+/// Close a completed escrow and its token vault, returning their rent to the buyer (permissionless)
+pub fn close_completed_escrow(purchase: &Purchase) -> Instruction {
+    let vault = purchase.vault();
+    
+    build(
+        payment_escrow::ID,
+        accounts::CloseCompletedEscrow {
+            buyer: purchase.buyer,
+            escrow_state: purchase.escrow(),
+            vault,
+            payment_token_mint: purchase.payment.mint(),
+            buyer_token_account: purchase.payment.token_account(&purchase.buyer),
+            vault_token_account: purchase.payment.token_account(&vault),
+            token_program: purchase.payment.token_program(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&payment_escrow::ID).0,
+            program: payment_escrow::ID,
+        },
+        instruction::CloseCompletedEscrow {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn purchase(payment: PaymentMode) -> Purchase {
+        let creator = Pubkey::new_unique();
+        let content_id = [9; 32];
+        
+        Purchase {
+            buyer: Pubkey::new_unique(),
+            escrow_seed: 4,
+            listing: pda::listing(&creator, &content_id, 1).0,
+            content_id,
+            access_mint_state: pda::access_mint_state(&creator, &content_id, 1).0,
+            access_mint: Pubkey::new_unique(),
+            access_mint_authority: pda::access_mint_authority(&creator, &content_id, 1).0,
+            split_state: pda::split(&creator, &content_id, 1).0,
+            payment,
+        }
+    }
+    
+    #[test]
+    fn sol_purchase_uses_placeholders() {
+        let purchase = purchase(PaymentMode::Sol);
+        let ix = buy_and_mint(&purchase, 10);
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        
+        // buyer, escrow, listing, platform config, vault, mint, buyer token, vault token, token program
+        assert_eq!(keys[0], purchase.buyer);
+        assert_eq!(keys[1], purchase.escrow());
+        assert_eq!(keys[4], purchase.vault());
+        assert_eq!(keys[5], System::id());
+        assert_eq!(keys[6], purchase.buyer);
+        assert_eq!(keys[7], purchase.vault());
+        assert_eq!(keys[8], System::id());
+        assert_eq!(ix.accounts.len(), 27);
+        assert!(ix.accounts[0].is_signer);
+    }
+    
+    #[test]
+    fn spl_purchase_uses_associated_token_accounts() {
+        let mint = Pubkey::new_unique();
+        let token_program = anchor_spl::token_2022::ID;
+        let purchase = purchase(PaymentMode::Spl { mint, token_program });
+        let ix = buy_and_mint(&purchase, 10);
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        
+        assert_eq!(keys[5], mint);
+        assert_eq!(keys[6], pda::token_account(&purchase.buyer, &mint, &token_program));
+        assert_eq!(keys[7], pda::token_account(&purchase.vault(), &mint, &token_program));
+        assert_eq!(keys[8], token_program);
+        assert!(keys.contains(&pda::token_account(&purchase.distribution_vault(), &mint, &token_program)));
+    }
+    
+    #[test]
+    fn settle_signs_with_payer() {
+        let purchase = purchase(PaymentMode::Sol);
+        let creator = Pubkey::new_unique();
+        let ix = settle(creator, &purchase);
+        
+        assert_eq!(ix.accounts[0].pubkey, creator);
+        assert!(ix.accounts[0].is_signer);
+        assert_eq!(ix.accounts[1].pubkey, purchase.buyer);
+        assert!(!ix.accounts[1].is_signer);
+    }
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+pub mod access;
+pub mod escrow;
+pub mod split;
+
+pub use access::*;
+pub use escrow::*;
+pub use split::*;
+
+/// Currency a listing or split is priced in
+///
+/// The programs take the same accounts in both modes. For SOL payments the mint and
+/// token program slots are filled with `System::id()` and every token account slot with
+/// its owner (the wallet or vault PDA itself), matching what the programs expect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaymentMode {
+    /// Native SOL
+    Sol,
+    /// SPL Token or Token-2022 mint; token accounts are associated token accounts
+    Spl { mint: Pubkey, token_program: Pubkey },
+}
+
+impl PaymentMode {
+    /// Payment mode of a listing or escrow (`None` = SOL)
+    /// `token_program` is only used for SPL mints
+    pub fn from_mint(mint: Option<Pubkey>, token_program: Pubkey) -> Self {
+        match mint {
+            None => PaymentMode::Sol,
+            Some(mint) => PaymentMode::Spl { mint, token_program },
+        }
+    }
+    
+    /// Payment mode of a split (`System::id()` = SOL)
+    pub fn from_split_mint(mint: Pubkey, token_program: Pubkey) -> Self {
+        Self::from_mint((mint != System::id()).then_some(mint), token_program)
+    }
+    
+    /// Mint as stored by listings and escrows (`None` = SOL)
+    pub fn listing_mint(&self) -> Option<Pubkey> {
+        match self {
+            PaymentMode::Sol => None,
+            PaymentMode::Spl { mint, .. } => Some(*mint),
+        }
+    }
+    
+    /// Payment mint account slot (`System::id()` for SOL)
+    pub fn mint(&self) -> Pubkey {
+        self.listing_mint().unwrap_or_else(System::id)
+    }
+    
+    /// Token program account slot (`System::id()` for SOL)
+    pub fn token_program(&self) -> Pubkey {
+        match self {
+            PaymentMode::Sol => System::id(),
+            PaymentMode::Spl { token_program, .. } => *token_program,
+        }
+    }
+    
+    /// Token account slot for `owner`: its associated token account, or `owner` itself for SOL
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        match self {
+            PaymentMode::Sol => *owner,
+            PaymentMode::Spl { mint, token_program } => crate::pda::token_account(owner, mint, token_program),
+        }
+    }
+}
+
+/// Create `owner`'s associated token account for `payment` if it does not exist yet (payer signs)
+/// `owner` may be a PDA such as a split or escrow vault. Returns `None` for SOL, which needs no token account
+pub fn create_token_account(payer: Pubkey, owner: Pubkey, payment: PaymentMode) -> Option<Instruction> {
+    match payment {
+        PaymentMode::Sol => None,
+        PaymentMode::Spl { mint, token_program } => Some(create_associated_token_account_idempotent(
+            &payer,
+            &owner,
+            &mint,
+            &token_program,
+        )),
+    }
+}
+
+/// Assemble an instruction from Anchor's generated account and argument structs
+fn build(program_id: Pubkey, accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::{associated_token, token_2022};
+    
+    #[test]
+    fn token_account_creation_follows_payment_mode() {
+        let payer = Pubkey::new_unique();
+        let vault = crate::pda::split_vault(&Pubkey::new_unique()).0;
+        assert!(create_token_account(payer, vault, PaymentMode::Sol).is_none());
+        
+        let payment = PaymentMode::Spl { mint: Pubkey::new_unique(), token_program: token_2022::ID };
+        let ix = create_token_account(payer, vault, payment).unwrap();
+        assert_eq!(ix.program_id, associated_token::ID);
+        assert_eq!(ix.accounts[0].pubkey, payer);
+        assert_eq!(ix.accounts[1].pubkey, payment.token_account(&vault));
+        assert_eq!(ix.accounts[2].pubkey, vault);
+    }
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{bpf_loader_upgradeable, instruction::Instruction};
+use distribution::state::{CollaboratorShare, DustPolicy, PlatformSettings, SplitState};
+use distribution::{accounts, instruction};
+use crate::pda;
+use super::{build, PaymentMode};
+
+/// Create the platform config singleton (distribution program upgrade authority only)
+pub fn initialize_platform_config(admin: Pubkey, settings: PlatformSettings) -> Instruction {
+    let program_data = Pubkey::find_program_address(
+        &[distribution::ID.as_ref()],
+        &bpf_loader_upgradeable::ID,
+    )
+    .0;
+    
+    build(
+        distribution::ID,
+        accounts::InitializePlatformConfig {
+            admin,
+            platform_config: pda::platform_config().0,
+            distribution_program: distribution::ID,
+            program_data,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::InitializePlatformConfig { settings },
+    )
+}
+
+/// Set or clear the platform-wide pause flag (platform admin only)
+pub fn set_platform_paused(admin: Pubkey, paused: bool) -> Instruction {
+    build(
+        distribution::ID,
+        accounts::UpdatePlatformConfig {
+            admin,
+            platform_config: pda::platform_config().0,
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::SetPlatformPaused { paused },
+    )
+}
+
+/// Create a split for content; `payment_token_mint` is `System::id()` for SOL splits
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_split(
+    creator: Pubkey,
+    content_id: [u8; 32],
+    platform_fee_bps: u16,
+    collaborators: Vec<CollaboratorShare>,
+    seed: u64,
+    distributor: Pubkey,
+    dust_policy: DustPolicy,
+    payment_token_mint: Pubkey,
+) -> Instruction {
+    let split_state = pda::split(&creator, &content_id, seed).0;
+    
+    build(
+        distribution::ID,
+        accounts::InitializeSplit {
+            creator,
+            platform_config: pda::platform_config().0,
+            payment_token_mint,
+            split_state,
+            vault: pda::split_vault(&split_state).0,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::InitializeSplit {
+            content_id,
+            platform_fee_bps,
+            collaborators,
+            seed,
+            distributor,
+            dust_policy,
+        },
+    )
+}
+
+/// Existing collaborators who must co-sign an update to `collaborators`:
+/// everyone whose share goes down, including collaborators being removed
+pub fn required_co_signers(current: &SplitState, collaborators: &[CollaboratorShare]) -> Vec<Pubkey> {
+    current
+        .collaborators
+        .iter()
+        .filter(|existing| {
+            let new_share = collaborators
+                .iter()
+                .find(|c| c.pubkey == existing.pubkey)
+                .map(|c| c.share_bps)
+                .unwrap_or(0);
+            new_share < existing.share_bps
+        })
+        .map(|existing| existing.pubkey)
+        .collect()
+}
+
+/// Update an unlocked split
+/// The collaborators returned by [`required_co_signers`] are appended as signing remaining accounts,
+/// in split order; each of them must sign the transaction
+pub fn update_split(
+    current: &SplitState,
+    collaborators: Vec<CollaboratorShare>,
+    platform_fee_bps: u16,
+    dust_policy: DustPolicy,
+    lock: bool,
+) -> Instruction {
+    let split_state = pda::split(&current.creator, &current.content_id, current.seed).0;
+    let co_signers = required_co_signers(current, &collaborators);
+    
+    let mut ix = build(
+        distribution::ID,
+        accounts::UpdateSplit {
+            creator: current.creator,
+            split_state,
+            platform_config: pda::platform_config().0,
+            system_program: System::id(),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::UpdateSplit {
+            platform_fee_bps,
+            collaborators,
+            dust_policy,
+            lock,
+        },
+    );
+    ix.accounts.extend(
+        co_signers
+            .into_iter()
+            .map(|pubkey| AccountMeta::new_readonly(pubkey, true)),
+    );
+    ix
+}
+
+/// Credit funds already in the split vault to the recipients (split distributor only)
+pub fn distribute(authority: Pubkey, split_state: Pubkey, payment: PaymentMode, amount: u64) -> Instruction {
+    let vault = pda::split_vault(&split_state).0;
+    
+    build(
+        distribution::ID,
+        accounts::Distribute {
+            authority,
+            split_state,
+            platform_config: pda::platform_config().0,
+            vault,
+            vault_token_account: payment.token_account(&vault),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::Distribute { amount },
+    )
+}
+
+/// Withdraw everything credited to `recipient` (creator, platform treasury or collaborator)
+pub fn claim(recipient: Pubkey, split_state: Pubkey, payment: PaymentMode) -> Instruction {
+    let vault = pda::split_vault(&split_state).0;
+    
+    build(
+        distribution::ID,
+        accounts::Claim {
+            recipient,
+            split_state,
+            vault,
+            payment_token_mint: payment.mint(),
+            vault_token_account: payment.token_account(&vault),
+            recipient_token_account: payment.token_account(&recipient),
+            token_program: payment.token_program(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::Claim {},
+    )
+}
+
+/// Retire a fully claimed split and reclaim its rent (creator only)
+pub fn close_split(creator: Pubkey, split_state: Pubkey, payment: PaymentMode) -> Instruction {
+    let vault = pda::split_vault(&split_state).0;
+    
+    build(
+        distribution::ID,
+        accounts::CloseSplit {
+            creator,
+            split_state,
+            vault,
+            payment_token_mint: payment.mint(),
+            vault_token_account: payment.token_account(&vault),
+            creator_token_account: payment.token_account(&creator),
+            token_program: payment.token_program(),
+            system_program: System::id(),
+            event_authority: pda::event_authority(&distribution::ID).0,
+            program: distribution::ID,
+        },
+        instruction::CloseSplit {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use distribution::state::Collaborator;
+    
+    fn split_with(collaborators: &[(Pubkey, u16)]) -> SplitState {
+        SplitState {
+            content_id: [1; 32],
+            creator: Pubkey::new_unique(),
+            platform_fee_bps: 250,
+            platform_treasury: Pubkey::new_unique(),
+            distributor: Pubkey::new_unique(),
+            locked: false,
+            payment_token_mint: System::id(),
+            creator_unclaimed: 0,
+            platform_unclaimed: 0,
+            dust_policy: DustPolicy::Creator,
+            carried_dust: 0,
+            held_amount: 0,
+            collaborators: collaborators
+                .iter()
+                .map(|(pubkey, share_bps)| Collaborator {
+                    pubkey: *pubkey,
+                    share_bps: *share_bps,
+                    unclaimed: 0,
+                })
+                .collect(),
+            last_distributed_ts: 0,
+            seed: 1,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn reduced_and_removed_collaborators_co_sign() {
+        let (kept, reduced, removed) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let current = split_with(&[(kept, 500), (reduced, 500), (removed, 500)]);
+        let added = Pubkey::new_unique();
+        
+        let ix = update_split(
+            &current,
+            vec![
+                CollaboratorShare { pubkey: kept, share_bps: 600 },
+                CollaboratorShare { pubkey: reduced, share_bps: 100 },
+                CollaboratorShare { pubkey: added, share_bps: 100 },
+            ],
+            250,
+            DustPolicy::Creator,
+            false,
+        );
+        
+        // creator, split state, platform config, system program, event authority, program,
+        // then co-signers in split order
+        let co_signers = &ix.accounts[6..];
+        assert_eq!(co_signers.len(), 2);
+        assert_eq!(co_signers[0].pubkey, reduced);
+        assert_eq!(co_signers[1].pubkey, removed);
+        assert!(co_signers.iter().all(|meta| meta.is_signer && !meta.is_writable));
+    }
+    
+    #[test]
+    fn claim_placeholders_follow_payment_mode() {
+        let split_state = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let vault = pda::split_vault(&split_state).0;
+        
+        let sol = claim(recipient, split_state, PaymentMode::Sol);
+        assert_eq!(sol.accounts[4].pubkey, vault);
+        assert_eq!(sol.accounts[5].pubkey, recipient);
+        
+        let mint = Pubkey::new_unique();
+        let token_program = anchor_spl::token::ID;
+        let spl = claim(recipient, split_state, PaymentMode::Spl { mint, token_program });
+        assert_eq!(spl.accounts[3].pubkey, mint);
+        assert_eq!(spl.accounts[4].pubkey, pda::token_account(&vault, &mint, &token_program));
+        assert_eq!(spl.accounts[5].pubkey, pda::token_account(&recipient, &mint, &token_program));
+        assert_eq!(spl.accounts[6].pubkey, token_program);
+    }
+}
@@ -0,0 +1,18 @@
+//! Rust client for the payment escrow, access mint and distribution programs
+//!
+//! - [`pda`] derives every program address
+//! - [`instructions`] builds instructions with their full, correctly ordered account lists
+//! - [`accounts`] decodes program accounts
+//! - [`error`] maps custom error codes back to the three program error enums
+
+pub mod accounts;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+
+pub use access_mint;
+pub use distribution;
+pub use payment_escrow;
+
+pub use error::{decode_error, OwnmarkError};
+pub use instructions::{PaymentMode, Purchase};
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use access_mint::state::{AccessMintState, AccessRecord};
+use distribution::state::{PlatformConfig, SplitState};
+use payment_escrow::state::{EscrowState, Listing};
+
+/// Seed of the escrow vault and the split vault
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Seed of the event authority PDA that every program signs its `emit_cpi!` events with
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Listing PDA: `["listing", creator, content_id, seed]`
+pub fn listing(creator: &Pubkey, content_id: &[u8; 32], seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Listing::SEED_PREFIX,
+            creator.as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+}
+
+/// Escrow state PDA: `["escrow", buyer, content_id, seed]`
+pub fn escrow(buyer: &Pubkey, content_id: &[u8; 32], seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            EscrowState::SEED_PREFIX,
+            buyer.as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+}
+
+/// Escrow vault PDA holding the buyer's payment: `["vault", escrow]`
+pub fn escrow_vault(escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, escrow.as_ref()], &payment_escrow::ID)
+}
+
+/// Escrow authority PDA, the authorized minter and distributor: `["escrow_authority"]`
+pub fn escrow_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EscrowState::AUTHORITY_SEED], &payment_escrow::ID)
+}
+
+/// Split state PDA: `["split", creator, content_id, seed]`
+pub fn split(creator: &Pubkey, content_id: &[u8; 32], seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SplitState::SEED_PREFIX,
+            creator.as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        &distribution::ID,
+    )
+}
+
+/// Split vault PDA holding distributed funds: `["vault", split_state]`
+pub fn split_vault(split_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, split_state.as_ref()], &distribution::ID)
+}
+
+/// Platform config singleton: `["platform_config"]`
+pub fn platform_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PlatformConfig::SEED], &distribution::ID)
+}
+
+/// Access mint state PDA: `["access_mint_state", creator, content_id, seed]`
+pub fn access_mint_state(creator: &Pubkey, content_id: &[u8; 32], seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AccessMintState::SEED_PREFIX,
+            creator.as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        &access_mint::ID,
+    )
+}
+
+/// Access mint authority PDA: `["access_mint_authority", creator, content_id, seed]`
+pub fn access_mint_authority(creator: &Pubkey, content_id: &[u8; 32], seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            creator.as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        &access_mint::ID,
+    )
+}
+
+/// Buyer's access record PDA: `["access_record", access_mint_state, buyer]`
+pub fn access_record(access_mint_state: &Pubkey, buyer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AccessRecord::SEED_PREFIX, access_mint_state.as_ref(), buyer.as_ref()],
+        &access_mint::ID,
+    )
+}
+
+/// Event authority PDA of `program_id`: `["__event_authority"]`
+pub fn event_authority(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id)
+}
+
+/// Metaplex metadata PDA of an access token mint
+pub fn metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    let metadata_program = anchor_spl::metadata::mpl_token_metadata::ID;
+    Pubkey::find_program_address(
+        &[b"metadata", metadata_program.as_ref(), mint.as_ref()],
+        &metadata_program,
+    )
+}
+
+/// Associated token account of `owner` for `mint` under `token_program`
+/// Used for buyer and vault token accounts of SPL payments and for access tokens
+pub fn token_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::pubkey::pubkey;
+    
+    const CONTENT_ID: [u8; 32] = [7; 32];
+    
+    #[test]
+    fn access_mint_authority_matches_program_derivation() {
+        let creator = Pubkey::new_unique();
+        let state = AccessMintState {
+            creator,
+            content_id: CONTENT_ID,
+            mint: Pubkey::new_unique(),
+            mint_authority: Pubkey::default(),
+            minter: Pubkey::new_unique(),
+            seed: 3,
+            total_minted: 0,
+            active_supply: 0,
+            created_ts: 0,
+            transfer_mode: access_mint::state::TransferMode::Transferable,
+            access_duration_secs: 0,
+            max_supply: None,
+            bump: 0,
+        };
+        
+        assert_eq!(
+            access_mint_authority(&creator, &CONTENT_ID, 3),
+            state.find_mint_authority(&access_mint::ID)
+        );
+    }
+    
+    /// Addresses for fixed inputs, pinned so that a changed seed or program id is caught
+    #[test]
+    fn derives_known_addresses() {
+        let creator = Pubkey::new_from_array([1; 32]);
+        let buyer = Pubkey::new_from_array([2; 32]);
+        
+        let listing = listing(&creator, &CONTENT_ID, 1).0;
+        let escrow = escrow(&buyer, &CONTENT_ID, 1).0;
+        let split = split(&creator, &CONTENT_ID, 1).0;
+        let access_mint_state = access_mint_state(&creator, &CONTENT_ID, 1).0;
+        
+        assert_eq!(listing, pubkey!("Hgo5FGgJGpRNUWnA3vZehjXYqySR2TYpKzj6vwXvctDr"));
+        assert_eq!(escrow, pubkey!("69soZuU8pUeYebr3Dm3xugZnY7CfTbyxepsUBw2TXJH2"));
+        assert_eq!(escrow_vault(&escrow).0, pubkey!("CNr1NHQkepw1QpTckB4w371hZeaB4dPLRVT6UJVhanU"));
+        assert_eq!(escrow_authority().0, pubkey!("B94GPUW8STX4q4iR2N334W4SrhD4D9pL59YpeSU6ra3Y"));
+        assert_eq!(split, pubkey!("9UTGYGUq1rq5rYLJB4tgfRVvbEMH3sTs9CwH8PLkGJU8"));
+        assert_eq!(split_vault(&split).0, pubkey!("AQ4iNSNgai4P4p3V6WDwqnzGhsaYbRdL4gxkYpTBVHMs"));
+        assert_eq!(platform_config().0, pubkey!("3i5tWh7XMrp1yb832GZ2A6RSMeb5cwRgCbUPJNiZVyVU"));
+        assert_eq!(access_mint_state, pubkey!("9LE7ifHG5fShaKMmRS3sHGaxwxoM9bNS8L8AgF9pfxAw"));
+        assert_eq!(
+            access_record(&access_mint_state, &buyer).0,
+            pubkey!("8NJgLqs75upDUfbEJDkCV6ZLQdW9YnV49jxXrJdrL1sa")
+        );
+        assert_eq!(
+            event_authority(&payment_escrow::ID).0,
+            pubkey!("6P3Uo1qbGBw5wgs98Wuq2JLFqFQWnJjtvrwXJM3BKVnS")
+        );
+        assert_eq!(
+            event_authority(&distribution::ID).0,
+            pubkey!("8CKCQBLikkwo4EKG7H9m8u57Syfs4tC2XCCd15rLArHT")
+        );
+        assert_eq!(
+            event_authority(&access_mint::ID).0,
+            pubkey!("CVpUomy6qtGjEmVqr8PDHWAUypGxqcN6QyNRo2km7a6c")
+        );
+    }
+}